@@ -0,0 +1,45 @@
+use cart_lin::{CartesianIndices, lin_to_cart_unchecked};
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+fn bench_shape<const N: usize>(c: &mut Criterion, dim_size: [usize; N]) {
+    let len: usize = dim_size.iter().product();
+
+    let mut group = c.benchmark_group(format!("cartesian_indices_{N}d"));
+    group.throughput(Throughput::Elements(len as u64));
+
+    // Reference: Decode each linear index separately (N divisions per element)
+    group.bench_function(BenchmarkId::new("lin_to_cart_unchecked", len), |b| {
+        b.iter(|| {
+            let dim_size = black_box(&dim_size);
+            for lin in 0..len {
+                black_box(lin_to_cart_unchecked(lin, dim_size));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("CartesianIndices::next", len), |b| {
+        b.iter(|| {
+            for cart in CartesianIndices::new(black_box(dim_size)) {
+                black_box(cart);
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("CartesianIndices::next_back", len), |b| {
+        b.iter(|| {
+            for cart in CartesianIndices::new(black_box(dim_size)).rev() {
+                black_box(cart);
+            }
+        })
+    });
+    group.finish();
+}
+
+fn cartesian_indices(c: &mut Criterion) {
+    bench_shape(c, [1000, 1000]);
+    bench_shape(c, [100, 99, 101]);
+    bench_shape(c, [30, 31, 32, 33]);
+    bench_shape(c, [15, 16, 16, 17, 17]);
+    bench_shape(c, [9, 10, 10, 10, 10, 11]);
+}
+
+criterion_group!(benches, cartesian_indices);
+criterion_main!(benches);
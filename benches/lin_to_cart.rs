@@ -0,0 +1,46 @@
+use cart_lin::{FastShape, Shape, lin_to_cart_unchecked};
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+fn bench_shape<const N: usize>(c: &mut Criterion, dim_size: [usize; N]) {
+    let len: usize = dim_size.iter().product();
+    let shape = Shape::new(dim_size);
+    let fast_shape = FastShape::new(dim_size);
+
+    let mut group = c.benchmark_group(format!("lin_to_cart_{N}d"));
+    group.throughput(Throughput::Elements(len as u64));
+
+    group.bench_function(BenchmarkId::new("lin_to_cart_unchecked", len), |b| {
+        b.iter(|| {
+            let dim_size = black_box(&dim_size);
+            for lin in 0..len {
+                black_box(lin_to_cart_unchecked(lin, dim_size));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("Shape::to_cart_unchecked", len), |b| {
+        b.iter(|| {
+            let shape = black_box(&shape);
+            for lin in 0..len {
+                black_box(shape.to_cart_unchecked(lin));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("FastShape::to_cart_unchecked", len), |b| {
+        b.iter(|| {
+            let fast_shape = black_box(&fast_shape);
+            for lin in 0..len {
+                black_box(fast_shape.to_cart_unchecked(lin));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn lin_to_cart(c: &mut Criterion) {
+    bench_shape(c, [1000, 1000]);
+    bench_shape(c, [100, 99, 101]);
+    bench_shape(c, [30, 31, 32, 33]);
+}
+
+criterion_group!(benches, lin_to_cart);
+criterion_main!(benches);
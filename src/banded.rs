@@ -0,0 +1,399 @@
+/*!
+Band storage of banded matrices.
+ */
+
+use crate::error::{IndexError, check_indices, check_linear};
+use crate::index_int::IndexInt;
+use crate::packed::triangular;
+use crate::traversal::{Traversal, Traverse, impl_traversal_iterator};
+
+/**
+Memory layout of a `rows x columns` matrix with `kl` sub-diagonals and `ku` super-diagonals in the band
+storage format of LAPACK (e.g. `dgbmv` or `dgbsv`).
+
+Each column of the matrix is stored in a column of a column-major array with `kl + ku + 1` rows (the leading
+dimension `ldab`), such that the diagonals of the matrix become the rows of the array: element `(i, j)` of
+the matrix is stored at `(ku + i - j) + j * ldab`, i.e. at `AB(ku + 1 + i - j, j)` in the 1-based notation
+of LAPACK. The entries of the array above the first and below the last element of a column do not belong
+to the matrix.
+```
+use cart_lin::{BandedLayout, IndexError};
+
+// Tridiagonal 4 x 4 matrix, stored as a 3 x 4 array (* marks unused entries):
+// [a b    ]      [* b e h]
+// [c d e  ]  ->  [a d g j]
+// [  f g h]      [c f i *]
+// [    i j]
+let layout = BandedLayout::new(4, 4, 1, 1).unwrap();
+assert_eq!(layout.leading_dimension(), 3);
+assert_eq!(layout.len(), 12);
+assert_eq!(layout.cart_to_lin(&[0, 0]).unwrap(), 1); // a
+assert_eq!(layout.cart_to_lin(&[1, 2]).unwrap(), 6); // e
+assert_eq!(layout.lin_to_cart(8).unwrap(), [3, 2]); // i
+assert_eq!(layout.cart_to_lin(&[3, 0]), Err(IndexError::NotStored { row: 3, column: 0 }));
+assert_eq!(layout.lin_to_cart(0), Err(IndexError::UnmappedOffset { offset: 0 }));
+```
+The LU factorization of LAPACK (`dgbtrf`) requires `kl` additional super-diagonals for fill-in, i.e.
+the layout of its input is created with `BandedLayout::new(rows, columns, kl, kl + ku)`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BandedLayout<T: IndexInt = usize> {
+    rows: T,
+    columns: T,
+    kl: T,
+    ku: T,
+    // Leading dimension of the band storage
+    ldab: T,
+    // Size of the band storage (including unused entries)
+    len: T,
+}
+
+impl<T: IndexInt> BandedLayout<T> {
+    /**
+    Creates a new [`BandedLayout`] of a `rows x columns` matrix with `kl` sub-diagonals and `ku`
+    super-diagonals.
+
+    If the size of the matrix or the number of sub- or super-diagonals is negative,
+    [`IndexError::NegativeDimension`] is returned (with axis `0` for `rows` and `kl` and axis `1` for
+    `columns` and `ku`). If the size of the band storage `(kl + ku + 1) * columns` does not fit into the
+    index type, [`IndexError::Overflow`] is returned.
+    ```
+    use cart_lin::{BandedLayout, IndexError};
+
+    assert_eq!(BandedLayout::<u8>::new(100, 85, 1, 1).unwrap().len(), 255);
+    assert_eq!(BandedLayout::<u8>::new(100, 86, 1, 1), Err(IndexError::Overflow));
+    assert_eq!(
+        BandedLayout::<i32>::new(10, 10, -1, 2),
+        Err(IndexError::NegativeDimension { axis: 0, size: -1 })
+    );
+    ```
+     */
+    pub fn new(rows: T, columns: T, kl: T, ku: T) -> Result<Self, IndexError<T>> {
+        for (axis, size) in [(0, rows), (1, columns), (0, kl), (1, ku)] {
+            if size.is_negative() {
+                return Err(IndexError::NegativeDimension { axis, size });
+            }
+        }
+        let ldab = kl
+            .checked_add(ku)
+            .and_then(|diagonals| diagonals.checked_add(T::ONE))
+            .ok_or(IndexError::Overflow)?;
+        let len = ldab.checked_mul(columns).ok_or(IndexError::Overflow)?;
+        return Ok(Self {
+            rows,
+            columns,
+            kl,
+            ku,
+            ldab,
+            len,
+        });
+    }
+
+    /**
+    Returns the size of the matrix as `[rows, columns]`.
+     */
+    pub fn dim_size(&self) -> [T; 2] {
+        return [self.rows, self.columns];
+    }
+
+    /**
+    Returns the number of sub-diagonals.
+     */
+    pub fn kl(&self) -> T {
+        return self.kl;
+    }
+
+    /**
+    Returns the number of super-diagonals.
+     */
+    pub fn ku(&self) -> T {
+        return self.ku;
+    }
+
+    /**
+    Returns the leading dimension `kl + ku + 1` of the band storage (`LDAB` in LAPACK).
+     */
+    pub fn leading_dimension(&self) -> T {
+        return self.ldab;
+    }
+
+    /**
+    Returns the size of the band storage `(kl + ku + 1) * columns`, including unused entries.
+     */
+    pub fn len(&self) -> T {
+        return self.len;
+    }
+
+    /**
+    Returns `true` if the band storage does not contain any entries.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == T::ZERO;
+    }
+
+    /**
+    Returns `true` if the element lies within the band, i.e. `column - ku <= row <= column + kl`.
+    The bounds of the matrix are not checked.
+     */
+    fn is_in_band(&self, row: T, column: T) -> bool {
+        if row <= column {
+            return column - row <= self.ku;
+        } else {
+            return row - column <= self.kl;
+        }
+    }
+
+    /**
+    Convert a cartesian index `[row, column]` into the index of the element within the band storage.
+
+    If any of the indices are out of bounds, this function returns [`IndexError::OutOfBounds`]. If the
+    element lies outside of the band, [`IndexError::NotStored`] is returned.
+    ```
+    use cart_lin::{BandedLayout, IndexError};
+
+    // 5 x 4 matrix with one sub-diagonal and two super-diagonals
+    let layout = BandedLayout::new(5, 4, 1, 2).unwrap();
+    assert_eq!(layout.cart_to_lin(&[1, 3]).unwrap(), 12);
+    assert_eq!(layout.cart_to_lin(&[4, 3]).unwrap(), 15);
+    assert_eq!(layout.cart_to_lin(&[0, 3]), Err(IndexError::NotStored { row: 0, column: 3 }));
+    assert_eq!(
+        layout.cart_to_lin(&[5, 3]),
+        Err(IndexError::OutOfBounds { axis: 0, index: 5, bound: 5 })
+    );
+    ```
+     */
+    pub fn cart_to_lin(&self, indices: &[T; 2]) -> Result<T, IndexError<T>> {
+        check_indices(indices, &[self.rows, self.columns])?;
+        let [row, column] = *indices;
+        if !self.is_in_band(row, column) {
+            return Err(IndexError::NotStored { row, column });
+        }
+        return Ok(self.cart_to_lin_unchecked(indices));
+    }
+
+    /**
+    Like [`BandedLayout::cart_to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might
+    be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::BandedLayout;
+
+    let layout = BandedLayout::new(5, 4, 1, 2).unwrap();
+    assert_eq!(layout.cart_to_lin_unchecked(&[1, 3]), 12);
+    ```
+     */
+    pub fn cart_to_lin_unchecked(&self, indices: &[T; 2]) -> T {
+        let [row, column] = *indices;
+        // (ku + row - column) + column * ldab, without intermediate results larger than the index
+        return column * (self.ldab - T::ONE) + self.ku + row;
+    }
+
+    /**
+    Convert the index of an entry of the band storage into the cartesian index `[row, column]` of the
+    element stored there.
+
+    If the index is negative or not smaller than the size of the band storage, this function returns
+    [`IndexError::LinearOutOfRange`]. If the entry does not belong to the matrix, i.e. it lies above the
+    first or below the last row of the matrix, [`IndexError::UnmappedOffset`] is returned.
+    ```
+    use cart_lin::{BandedLayout, IndexError};
+
+    let layout = BandedLayout::new(5, 4, 1, 2).unwrap();
+    assert_eq!(layout.lin_to_cart(12).unwrap(), [1, 3]);
+    assert_eq!(layout.lin_to_cart(1), Err(IndexError::UnmappedOffset { offset: 1 }));
+    assert_eq!(
+        layout.lin_to_cart(16),
+        Err(IndexError::LinearOutOfRange { index: 16, len: 16 })
+    );
+    ```
+     */
+    pub fn lin_to_cart(&self, index: T) -> Result<[T; 2], IndexError<T>> {
+        check_linear(index, self.len)?;
+        let column = index / self.ldab;
+        let band_row = index % self.ldab;
+        // The matrix row is column + band_row - ku, which must lie within 0..rows
+        let is_mapped = if band_row >= self.ku {
+            band_row - self.ku < self.rows - column.min(self.rows)
+        } else {
+            self.ku - band_row <= column && column - (self.ku - band_row) < self.rows
+        };
+        if !is_mapped {
+            return Err(IndexError::UnmappedOffset { offset: index });
+        }
+        return Ok(self.lin_to_cart_unchecked(index));
+    }
+
+    /**
+    Like [`BandedLayout::lin_to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the cartesian index received from this
+    function might be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::BandedLayout;
+
+    let layout = BandedLayout::new(5, 4, 1, 2).unwrap();
+    assert_eq!(layout.lin_to_cart_unchecked(12), [1, 3]);
+    ```
+     */
+    pub fn lin_to_cart_unchecked(&self, index: T) -> [T; 2] {
+        let column = index / self.ldab;
+        let row = column.wrapping_add(index % self.ldab).wrapping_sub(self.ku);
+        return [row, column];
+    }
+
+    /**
+    Returns an iterator over the cartesian indices of all elements within the band, in memory order (see
+    [`BandedIndices`]).
+    ```
+    use cart_lin::BandedLayout;
+
+    let layout = BandedLayout::new(3, 3, 1, 0).unwrap();
+    assert_eq!(
+        layout.indices().collect::<Vec<_>>(),
+        [[0, 0], [1, 0], [1, 1], [2, 1], [2, 2]]
+    );
+    ```
+     */
+    pub fn indices(&self) -> BandedIndices<T> {
+        let count = self.elements_before(self.nonempty_columns());
+        return BandedIndices {
+            traversal: Traversal::new(*self, count, [T::ZERO; 2]),
+        };
+    }
+
+    /**
+    Returns the number of columns containing at least one element within the band, i.e. `min(columns,
+    rows + ku)`. All following columns lie entirely below the matrix.
+     */
+    fn nonempty_columns(&self) -> T {
+        if self.columns > self.ku && self.columns - self.ku > self.rows {
+            return self.rows + self.ku;
+        } else {
+            return self.columns;
+        }
+    }
+
+    /**
+    Returns the first row within the band of a column.
+     */
+    fn first_row(&self, column: T) -> T {
+        if column > self.ku {
+            return column - self.ku;
+        } else {
+            return T::ZERO;
+        }
+    }
+
+    /**
+    Returns the number of elements within the band in all columns before `column`, which must not be
+    larger than [`BandedLayout::nonempty_columns`].
+     */
+    fn elements_before(&self, column: T) -> T {
+        // Each nonempty column has ldab entries in the band storage, of which some lie above the first row
+        // (ku - c in column c < ku) or below the last row (c + kl + 1 - rows in column c >= rows - kl - 1)
+        // of the matrix. Both numbers are bounded by the size of the band storage.
+        let above = column.min(self.ku);
+        let unused_above = above * (self.ku + T::ONE) - triangular(above);
+        let unused_below = if self.rows > self.kl {
+            let first = self.rows - self.kl - T::ONE;
+            if column > first {
+                triangular(column - first - T::ONE)
+            } else {
+                T::ZERO
+            }
+        } else if column > T::ZERO {
+            column * (self.kl - self.rows) + triangular(column)
+        } else {
+            T::ZERO
+        };
+        return column * self.ldab - unused_above - unused_below;
+    }
+
+    /**
+    Returns the cartesian index of the element at the given position in memory order, which must be smaller
+    than the number of elements within the band.
+     */
+    fn position_to_cart(&self, position: T) -> [T; 2] {
+        // Binary search for the last column starting at or before the position
+        let two = T::ONE + T::ONE;
+        let mut lowest = T::ZERO;
+        let mut highest = self.nonempty_columns() - T::ONE;
+        while lowest < highest {
+            let middle = lowest + (highest - lowest + T::ONE) / two;
+            if self.elements_before(middle) <= position {
+                lowest = middle;
+            } else {
+                highest = middle - T::ONE;
+            }
+        }
+        let row = self.first_row(lowest) + (position - self.elements_before(lowest));
+        return [row, lowest];
+    }
+}
+
+/**
+An iterator over the cartesian indices of all elements within the band of a [`BandedLayout`], in memory
+order (column by column).
+
+This struct is created by [`BandedLayout::indices`]. Like [`CartesianIndices`](crate::CartesianIndices), it
+supports random access via [`Iterator::nth`] and traversal from the back:
+```
+use cart_lin::BandedLayout;
+
+// Tridiagonal 4 x 4 matrix
+let mut cartiter = BandedLayout::new(4, 4, 1, 1).unwrap().indices();
+assert_eq!(cartiter.len(), 10);
+assert_eq!(cartiter.next(), Some([0, 0]));
+assert_eq!(cartiter.nth(2), Some([1, 1]));
+assert_eq!(cartiter.next_back(), Some([3, 3]));
+assert_eq!(cartiter.nth_back(1), Some([3, 2]));
+assert_eq!(cartiter.len(), 3);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct BandedIndices<T: IndexInt = usize> {
+    traversal: Traversal<BandedLayout<T>, T>,
+}
+
+// The cursor is the cartesian index itself
+impl<T: IndexInt> Traverse<T> for BandedLayout<T> {
+    type Cursor = [T; 2];
+    type Item = [T; 2];
+
+    fn cursor_at(&self, position: T) -> [T; 2] {
+        return self.position_to_cart(position);
+    }
+
+    fn advance(&self, cart: &mut [T; 2]) {
+        let [row, column] = *cart;
+        if row + T::ONE < self.rows && self.is_in_band(row + T::ONE, column) {
+            cart[0] = row + T::ONE;
+        } else {
+            *cart = [self.first_row(column + T::ONE), column + T::ONE];
+        }
+    }
+
+    fn retreat(&self, cart: &mut [T; 2]) {
+        let [row, column] = *cart;
+        if row > self.first_row(column) {
+            cart[0] = row - T::ONE;
+        } else {
+            // Last row of the previous column: min(rows - 1, column - 1 + kl)
+            let column = column - T::ONE;
+            let last = self.rows - T::ONE;
+            let row = if last <= column || last - column <= self.kl {
+                last
+            } else {
+                column + self.kl
+            };
+            *cart = [row, column];
+        }
+    }
+
+    fn item(&self, cart: &[T; 2]) -> [T; 2] {
+        return *cart;
+    }
+}
+
+impl_traversal_iterator!([T: IndexInt] BandedIndices<T>, T, [T; 2]);
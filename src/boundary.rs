@@ -0,0 +1,210 @@
+/*!
+Boundary handling for cartesian indices outside of the bounds of an array (e.g. in stencil computations).
+ */
+
+use crate::error::{IndexError, validate_shape};
+use crate::index_int::IndexInt;
+
+/**
+Selects how [`cart_to_lin_boundary`] treats an index outside of the bounds of an axis.
+
+For an axis of size 4, the indices `-3..7` are resolved as follows:
+
+| Index            | -3   | -2   | -1   | 0 | 1 | 2 | 3 | 4    | 5    | 6    |
+|------------------|------|------|------|---|---|---|---|------|------|------|
+| [`Error`]        | err  | err  | err  | 0 | 1 | 2 | 3 | err  | err  | err  |
+| [`Wrap`]         | 1    | 2    | 3    | 0 | 1 | 2 | 3 | 0    | 1    | 2    |
+| [`Clamp`]        | 0    | 0    | 0    | 0 | 1 | 2 | 3 | 3    | 3    | 3    |
+| [`Reflect`]      | 3    | 2    | 1    | 0 | 1 | 2 | 3 | 2    | 1    | 0    |
+| [`Constant`]     | none | none | none | 0 | 1 | 2 | 3 | none | none | none |
+
+[`Error`]: Boundary::Error
+[`Wrap`]: Boundary::Wrap
+[`Clamp`]: Boundary::Clamp
+[`Reflect`]: Boundary::Reflect
+[`Constant`]: Boundary::Constant
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// Indices outside of the bounds are rejected with [`IndexError::OutOfBounds`], like
+    /// [`cart_to_lin`](crate::cart_to_lin) does.
+    #[default]
+    Error,
+    /// Periodic boundary: the index is taken modulo the size of the axis.
+    Wrap,
+    /// The index is clamped to the first or last element of the axis.
+    Clamp,
+    /// The index is mirrored at the first and last element of the axis, without repeating them (`-1`
+    /// becomes `1`). This is called `reflect` in NumPy and `mirror` in SciPy.
+    Reflect,
+    /// Indices outside of the bounds refer to a constant padding value, which is signaled by `None`. This is
+    /// the only mode that accepts indices of axes of size zero.
+    Constant,
+}
+
+impl Boundary {
+    /**
+    Resolves an index of an axis of the given (non-negative) size. Returns `Ok(None)` for out-of-bounds
+    indices of [`Boundary::Constant`] and `Err(())` if the index cannot be resolved.
+     */
+    fn resolve<T: IndexInt>(self, index: T, size: T) -> Result<Option<T>, ()> {
+        if !index.is_negative() && index < size {
+            return Ok(Some(index));
+        }
+        if self == Boundary::Constant {
+            return Ok(None);
+        }
+        if size == T::ZERO {
+            return Err(());
+        }
+        match self {
+            Boundary::Error | Boundary::Constant => return Err(()),
+            Boundary::Wrap => {
+                let rest = index % size;
+                if rest.is_negative() {
+                    return Ok(Some(rest + size));
+                } else {
+                    return Ok(Some(rest));
+                }
+            }
+            Boundary::Clamp => {
+                if index.is_negative() {
+                    return Ok(Some(T::ZERO));
+                } else {
+                    return Ok(Some(size - T::ONE));
+                }
+            }
+            Boundary::Reflect => return Ok(Some(reflect(index, size - T::ONE))),
+        }
+    }
+}
+
+/**
+Mirrors an index at `0` and `last` (without repeating them) until it lies within `0..=last`.
+ */
+fn reflect<T: IndexInt>(index: T, last: T) -> T {
+    if last == T::ZERO {
+        return T::ZERO;
+    }
+    match last.checked_mul(T::ONE + T::ONE) {
+        Some(period) => {
+            let mut rest = index % period;
+            if rest.is_negative() {
+                rest += period;
+            }
+            if rest <= last {
+                return rest;
+            } else {
+                return period - rest;
+            }
+        }
+        // If the period does not fit into T, at most two reflections are required
+        None => {
+            if index > last {
+                return last - (index - last);
+            }
+            // The index is negative, so -index = last - distance does not overflow
+            let distance = last + index;
+            if distance.is_negative() {
+                // Second reflection at last: period + index
+                return last + distance;
+            } else {
+                return last - distance;
+            }
+        }
+    }
+}
+
+/**
+Convert a cartesian index into a linear index (row-major), resolving indices outside of the bounds of each
+axis according to its [`Boundary`] mode.
+
+Like [`cart_to_lin`](crate::cart_to_lin), this function takes the cartesian index and the size of each
+dimension as slices, plus one [`Boundary`] mode per axis. The cartesian index is signed, which allows
+negative offsets from the edges of the array, as they occur in stencil computations. It returns `Ok(None)`
+if an index is out of the bounds of an axis with [`Boundary::Constant`], i.e. the element is a padding
+value. This includes axes of size zero, whose indices are all padding values. If an index is out of the
+bounds of an axis with [`Boundary::Error`], or of an axis of size zero with any mode other than
+[`Boundary::Constant`], [`IndexError::OutOfBounds`] is returned, even if another axis contains a padding
+value. If the lengths of the slices do not match, [`IndexError::DimensionMismatch`] is returned.
+
+Since no allocation can be larger than `isize::MAX` bytes, the number of elements of the shape must fit
+into an `isize` (otherwise, [`IndexError::Overflow`] is returned). The errors therefore report the size of
+an axis as `isize`, like the offending index.
+```
+use cart_lin::{Boundary, IndexError, cart_to_lin_boundary};
+
+// 3 x 4 grid, periodic along the first axis and clamped along the second axis
+let dim_size = [3, 4];
+let boundary = [Boundary::Wrap, Boundary::Clamp];
+assert_eq!(cart_to_lin_boundary(&[1, 2], &dim_size, &boundary), Ok(Some(6)));
+assert_eq!(cart_to_lin_boundary(&[-1, 2], &dim_size, &boundary), Ok(Some(10)));
+assert_eq!(cart_to_lin_boundary(&[3, 4], &dim_size, &boundary), Ok(Some(3)));
+
+// Five-point stencil around [0, 0] with zero padding
+let boundary = [Boundary::Constant; 2];
+let field = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+let sum: f64 = [[0, 0], [-1, 0], [1, 0], [0, -1], [0, 1]]
+    .iter()
+    .map(|cart| match cart_to_lin_boundary(cart, &dim_size, &boundary).unwrap() {
+        Some(index) => field[index],
+        None => 0.0,
+    })
+    .sum();
+assert_eq!(sum, 1.0 + 5.0 + 2.0);
+
+assert_eq!(
+    cart_to_lin_boundary(&[-1, 0], &dim_size, &[Boundary::Error; 2]),
+    Err(IndexError::OutOfBounds { axis: 0, index: -1, bound: 3 })
+);
+```
+ */
+pub fn cart_to_lin_boundary(
+    indices: &[isize],
+    dim_size: &[usize],
+    boundary: &[Boundary],
+) -> Result<Option<usize>, IndexError<isize>> {
+    for got in [indices.len(), boundary.len()] {
+        if got != dim_size.len() {
+            return Err(IndexError::DimensionMismatch {
+                expected: dim_size.len(),
+                got,
+            });
+        }
+    }
+    // All sizes and linear indices must fit into an isize. The sizes are checked separately, since they may
+    // exceed the number of elements if another axis has size zero.
+    let fits_isize = |size: usize| isize::try_from(size).is_ok();
+    match validate_shape(dim_size) {
+        Ok(len) if fits_isize(len) && dim_size.iter().all(|size| fits_isize(*size)) => (),
+        _ => return Err(IndexError::Overflow),
+    }
+
+    // Out-of-bounds indices of other axes are reported even if the element is a padding value
+    let mut lin: isize = 0;
+    let mut is_padding = false;
+    for (axis, ((index, size), mode)) in indices
+        .iter()
+        .zip(dim_size.iter())
+        .zip(boundary.iter())
+        .enumerate()
+    {
+        let size = *size as isize;
+        match mode.resolve(*index, size) {
+            Ok(Some(resolved)) => lin = lin * size + resolved,
+            Ok(None) => is_padding = true,
+            Err(()) => {
+                return Err(IndexError::OutOfBounds {
+                    axis,
+                    index: *index,
+                    bound: size,
+                });
+            }
+        }
+    }
+    if is_padding {
+        return Ok(None);
+    } else {
+        return Ok(Some(lin as usize));
+    }
+}
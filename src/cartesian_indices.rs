@@ -0,0 +1,1168 @@
+/*!
+An iterator over all cartesian indices within a box of dimension sizes respectively bounds.
+ */
+
+use core::iter::FusedIterator;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::error::{IndexError, validate_shape};
+use crate::index_int::IndexInt;
+use crate::order::PermutedLayout;
+
+/**
+First and last index of an axis (in iteration order), its number of indices and the step between two
+consecutive indices. For an axis traversed in descending order, `step` holds the (wrapping) negation of
+the step, so that all index calculations are wrapping additions independent of the direction.
+ */
+#[derive(Debug, Clone, Copy)]
+struct Axis<T> {
+    first: T,
+    last: T,
+    count: T,
+    step: T,
+}
+
+impl<T: IndexInt> Axis<T> {
+    /**
+    Returns the index at the given position along the axis.
+     */
+    #[inline]
+    fn at(&self, position: T) -> T {
+        return self.first.wrapping_add(position.wrapping_mul(self.step));
+    }
+
+    /**
+    Moves the index one step towards the last index. If the index already is the last index, it wraps
+    around to the first index and `false` is returned.
+     */
+    #[inline]
+    fn advance(&self, index: &mut T) -> bool {
+        if *index != self.last {
+            *index = index.wrapping_add(self.step);
+            return true;
+        }
+        *index = self.first;
+        return false;
+    }
+
+    /**
+    Moves the index one step towards the first index, the inverse of [`Axis::advance`].
+     */
+    #[inline]
+    fn retreat(&self, index: &mut T) -> bool {
+        if *index != self.first {
+            *index = index.wrapping_sub(self.step);
+            return true;
+        }
+        *index = self.last;
+        return false;
+    }
+
+    /**
+    Returns the same axis traversed in the opposite direction.
+     */
+    fn reversed(self) -> Self {
+        return Self {
+            first: self.last,
+            last: self.first,
+            count: self.count,
+            step: T::ZERO.wrapping_sub(self.step),
+        };
+    }
+}
+
+/**
+Checks whether the lower bound of each axis is smaller than its upper bound, whether each step is
+positive and whether the total number of elements fits into the index type. The number of indices of
+each axis is written into `counts`.
+ */
+fn check_bounds<T: IndexInt>(bounds: &[[T; 3]], counts: &mut [T]) -> Result<(), IndexError<T>> {
+    for (axis, (&[start, end, step], count)) in bounds.iter().zip(counts.iter_mut()).enumerate() {
+        if end <= start {
+            return Err(IndexError::InvalidBounds { axis, start, end });
+        }
+        if step <= T::ZERO {
+            return Err(IndexError::InvalidStep { axis, step });
+        }
+        let extent = end.checked_sub(start).ok_or(IndexError::Overflow)?;
+        *count = (extent - T::ONE) / step + T::ONE;
+    }
+    validate_shape(counts)?;
+    return Ok(());
+}
+
+/**
+Writes the ascending axes described by `bounds` into `axes` and returns the total number of elements.
+Like [`Iterator::step_by`], the number of indices of an axis is `ceil((end - start) / step)`. All values
+wrap around for invalid bounds.
+
+# Panics
+
+Panics if a step is zero.
+ */
+fn axes_unchecked<T: IndexInt>(bounds: &[[T; 3]], axes: &mut [Axis<T>]) -> T {
+    let mut max = T::ONE;
+    for (&[start, end, step], axis) in bounds.iter().zip(axes.iter_mut()) {
+        let extent = end.wrapping_sub(start);
+        let count = if extent <= T::ZERO {
+            T::ZERO
+        } else {
+            (extent - T::ONE) / step + T::ONE
+        };
+        max = max.wrapping_mul(count);
+
+        // The last index is never accessed if the axis is empty, hence wrapping is fine
+        *axis = Axis {
+            first: start,
+            last: start.wrapping_add(count.wrapping_sub(T::ONE).wrapping_mul(step)),
+            count,
+            step,
+        };
+    }
+    return max;
+}
+
+/**
+Writes the cartesian index at the given linear position into `index`. The linear position is counted in
+traversal order, `fastest_first` lists the axes starting with the fastest varying one.
+ */
+fn decode_position<T: IndexInt>(
+    position: T,
+    axes: &[Axis<T>],
+    fastest_first: impl Iterator<Item = usize>,
+    index: &mut [T],
+) {
+    let mut rest = position;
+    for axis_index in fastest_first {
+        let axis = &axes[axis_index];
+        index[axis_index] = axis.at(rest % axis.count);
+        rest /= axis.count;
+    }
+}
+
+/**
+Increments the cartesian index like an odometer, starting with the fastest varying axis.
+Returns the axis which has been advanced (all faster varying axes wrapped around to their first index).
+After the last element, the index wraps around to the first element and `None` is returned.
+ */
+#[inline]
+fn increment<T: IndexInt>(
+    index: &mut [T],
+    axes: &[Axis<T>],
+    mut fastest_first: impl Iterator<Item = usize>,
+) -> Option<usize> {
+    return fastest_first.find(|&axis_index| axes[axis_index].advance(&mut index[axis_index]));
+}
+
+/**
+Decrements the cartesian index like an odometer, the inverse of [`increment`].
+ */
+#[inline]
+fn decrement<T: IndexInt>(
+    index: &mut [T],
+    axes: &[Axis<T>],
+    mut fastest_first: impl Iterator<Item = usize>,
+) -> Option<usize> {
+    return fastest_first.find(|&axis_index| axes[axis_index].retreat(&mut index[axis_index]));
+}
+
+/**
+An iterator over all cartesian indices within the input dimension sizes.
+
+The iterator stores the cartesian indices of its front and back element and advances them like an
+odometer (incrementing the last axis and propagating the carry), which requires amortized O(1) work
+per element. Random access via [`Iterator::nth`] and [`DoubleEndedIterator::nth_back`] is O(N).
+
+The indices are of the integer type `T` (`usize` by default, see [`IndexInt`]):
+```
+use cart_lin::CartesianIndices;
+
+let mut cartiter = CartesianIndices::<2, u32>::new([2, 3]);
+assert_eq!(cartiter.next(), Some([0u32, 0]));
+assert_eq!(cartiter.next(), Some([0u32, 1]));
+```
+ */
+#[derive(Debug, Clone)]
+pub struct CartesianIndices<const N: usize, T: IndexInt = usize> {
+    // Linear position of the next element from the front
+    current: T,
+    // Linear position one past the next element from the back
+    max: T,
+    // Cartesian index of the next element from the front
+    front: [T; N],
+    // Cartesian index of the next element from the back
+    back: [T; N],
+    axes: [Axis<T>; N],
+    // Traversal order of the axes, from the slowest to the fastest varying axis
+    order: [usize; N],
+}
+
+impl<const N: usize, T: IndexInt> CartesianIndices<N, T> {
+    /**
+    Creates a new `CartesianIndices` iterator using the given dimension sizes.
+    ```
+    use cart_lin::CartesianIndices;
+
+    let mut cartiter = CartesianIndices::new([3]);
+    assert_eq!(cartiter.next(), Some([0]));
+    assert_eq!(cartiter.next(), Some([1]));
+    assert_eq!(cartiter.next(), Some([2]));
+    assert_eq!(cartiter.next(), None);
+
+    let mut cartiter = CartesianIndices::new([1, 3]);
+    assert_eq!(cartiter.next(), Some([0, 0]));
+    assert_eq!(cartiter.next(), Some([0, 1]));
+    assert_eq!(cartiter.next(), Some([0, 2]));
+    assert_eq!(cartiter.next(), None);
+    ```
+
+    # Panics
+
+    Panics if a dimension size is negative or if the total number of elements overflows the index type
+    (see [`validate_shape`]). Use [`CartesianIndices::try_new`] for untrusted input.
+     */
+    pub fn new(dim_size: [T; N]) -> Self {
+        match Self::try_new(dim_size) {
+            Ok(cartiter) => return cartiter,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /**
+    Like [`CartesianIndices::new`], but returns an error instead of panicking if a dimension size is
+    negative or if the shape overflows (see [`validate_shape`]).
+    ```
+    use cart_lin::{CartesianIndices, IndexError};
+
+    assert_eq!(CartesianIndices::try_new([2, 3]).unwrap().len(), 6);
+    assert_eq!(
+        CartesianIndices::try_new([usize::MAX, 2]).unwrap_err(),
+        IndexError::Overflow
+    );
+    assert_eq!(
+        CartesianIndices::<2, i32>::try_new([2, -3]).unwrap_err(),
+        IndexError::NegativeDimension { axis: 1, size: -3 }
+    );
+    ```
+     */
+    pub fn try_new(dim_size: [T; N]) -> Result<Self, IndexError<T>> {
+        validate_shape(&dim_size)?;
+
+        let mut bounds = [[T::ZERO, T::ZERO]; N];
+        for (limits, dim) in bounds.iter_mut().zip(dim_size) {
+            limits[1] = dim;
+        }
+
+        return Ok(Self::from_bounds_unchecked(bounds));
+    }
+
+    /**
+    Creates a new [`CartesianIndices`] using lower and upper bounds of each dimension.
+
+    The lower and upper bounds must be given as an two-element array and the lower
+    bound must be smaller than the upper bound. Additionally, the total number of elements
+    must fit into the index type. Otherwise, an [`IndexError`] is returned:
+    ```
+    use cart_lin::{CartesianIndices, IndexError};
+
+    // Valid input:
+    // Indices for first dimension are between 1 and 3 (excluded)
+    // Indices for second dimension are between 2 and 3 (excluded)
+    let mut cartiter = CartesianIndices::from_bounds([[1, 3], [2, 5]]).expect("bounds must be strictly monotonic increasing");
+    assert_eq!(cartiter.next(), Some([1, 2]));
+    assert_eq!(cartiter.next(), Some([1, 3]));
+    assert_eq!(cartiter.next(), Some([1, 4]));
+    assert_eq!(cartiter.next(), Some([2, 2]));
+    assert_eq!(cartiter.next(), Some([2, 3]));
+    assert_eq!(cartiter.next(), Some([2, 4]));
+    assert_eq!(cartiter.next(), None);
+
+    // Invalid input:
+    // Lower bound for first dimension is 1, but upper bound is 0?
+    assert!(CartesianIndices::from_bounds([[1, 0], [2, 3]]).is_err());
+
+    // Invalid input:
+    // Lower bound for first dimension is 1, but upper bound is also 1?
+    assert_eq!(
+        CartesianIndices::from_bounds([[1, 1], [2, 3]]).unwrap_err(),
+        IndexError::InvalidBounds { axis: 0, start: 1, end: 1 }
+    );
+
+    // Invalid input:
+    // The total number of elements does not fit into an usize
+    assert_eq!(
+        CartesianIndices::from_bounds([[0, usize::MAX], [0, 2]]).unwrap_err(),
+        IndexError::Overflow
+    );
+    ```
+     */
+    pub fn from_bounds(bounds: [[T; 2]; N]) -> Result<Self, IndexError<T>> {
+        return Self::from_bounds_with_step(bounds.map(|[start, end]| [start, end, T::ONE]));
+    }
+
+    /**
+    Like [`CartesianIndices::from_bounds`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid. Using
+    such an invalid index may cause an out-of-bounds read. If the total number of elements overflows the index
+    type, it wraps around and the iterator yields only a part of the cartesian indices.
+    */
+    pub fn from_bounds_unchecked(bounds: [[T; 2]; N]) -> Self {
+        return Self::from_bounds_with_step_unchecked(
+            bounds.map(|[start, end]| [start, end, T::ONE]),
+        );
+    }
+
+    /**
+    Creates a new [`CartesianIndices`] using the lower bound, the upper bound and the step of each dimension.
+
+    Each axis is given as a three-element array `[start, end, step]` and yields the indices `start`,
+    `start + step`, `start + 2 * step` and so on, as long as they are smaller than `end`. The number of
+    indices along an axis is therefore `ceil((end - start) / step)`, like for
+    `(start..end).step_by(step)`. Besides the requirements of [`CartesianIndices::from_bounds`], each step
+    must be positive. Otherwise, [`IndexError::InvalidStep`] is returned:
+    ```
+    use cart_lin::{CartesianIndices, IndexError};
+
+    // Every second row and every third column of a 4 x 7 matrix
+    let mut cartiter = CartesianIndices::from_bounds_with_step([[0, 4, 2], [0, 7, 3]]).unwrap();
+    assert_eq!(cartiter.len(), 6);
+    assert_eq!(cartiter.next(), Some([0, 0]));
+    assert_eq!(cartiter.next(), Some([0, 3]));
+    assert_eq!(cartiter.next(), Some([0, 6]));
+    assert_eq!(cartiter.next(), Some([2, 0]));
+    assert_eq!(cartiter.next_back(), Some([2, 6]));
+    assert_eq!(cartiter.nth(0), Some([2, 3]));
+    assert_eq!(cartiter.next(), None);
+
+    assert_eq!(
+        CartesianIndices::from_bounds_with_step([[0, 4, 2], [0, 7, 0]]).unwrap_err(),
+        IndexError::InvalidStep { axis: 1, step: 0 }
+    );
+    ```
+     */
+    pub fn from_bounds_with_step(bounds: [[T; 3]; N]) -> Result<Self, IndexError<T>> {
+        check_bounds(&bounds, &mut [T::ZERO; N])?;
+        return Ok(Self::from_bounds_with_step_unchecked(bounds));
+    }
+
+    /**
+    Like [`CartesianIndices::from_bounds_with_step`], but without the checks. See
+    [`CartesianIndices::from_bounds_unchecked`].
+
+    # Panics
+
+    Panics if a step is zero.
+    */
+    pub fn from_bounds_with_step_unchecked(bounds: [[T; 3]; N]) -> Self {
+        return Self::from_bounds_with_step_ordered(
+            bounds,
+            PermutedLayout::row_major(),
+            [false; N],
+        );
+    }
+
+    /**
+    Pairs each cartesian index with its row-major linear index into an array with the dimension sizes
+    `dim_size` (the parent array). In contrast to [`Iterator::enumerate`], this also yields the correct
+    linear index if the iterator only covers a sub-box of the parent array (see
+    [`CartesianIndices::from_bounds`]) or has steps, a custom traversal order or reversed axes (see
+    [`CartesianIndicesBuilder`]):
+    ```
+    use cart_lin::{CartesianIndices, cart_to_lin};
+
+    // Interior of a 4 x 5 array
+    let dim_size = [4, 5];
+    let mut cartiter = CartesianIndices::from_bounds([[1, 3], [1, 4]]).unwrap().enumerate_linear(dim_size).unwrap();
+    assert_eq!(cartiter.next(), Some((6, [1, 1])));
+    assert_eq!(cartiter.next(), Some((7, [1, 2])));
+    assert_eq!(cartiter.next(), Some((8, [1, 3])));
+    assert_eq!(cartiter.next(), Some((11, [2, 1])));
+    for (lin, cart) in cartiter {
+        assert_eq!(cart_to_lin(&cart, &dim_size), Ok(lin));
+    }
+    ```
+    The linear index is updated incrementally: Advancing an axis (while all faster varying axes wrap
+    around) always changes the linear index by the same amount, which is precomputed for each axis.
+
+    If `dim_size` is not a valid shape (see [`validate_shape`]) or if a cartesian index of the box is
+    out of bounds of the parent array, an [`IndexError`] is returned:
+    ```
+    use cart_lin::{CartesianIndices, IndexError};
+
+    assert_eq!(
+        CartesianIndices::from_bounds([[1, 3], [1, 6]]).unwrap().enumerate_linear([4, 5]).unwrap_err(),
+        IndexError::OutOfBounds { axis: 1, index: 5, bound: 5 }
+    );
+    ```
+     */
+    pub fn enumerate_linear(
+        self,
+        dim_size: [T; N],
+    ) -> Result<EnumerateLinear<N, T>, IndexError<T>> {
+        validate_shape(&dim_size)?;
+
+        // The bounds of an empty iterator are never accessed
+        if self.current != self.max {
+            for (axis_index, (axis, bound)) in self.axes.iter().zip(dim_size).enumerate() {
+                for index in [axis.first, axis.last] {
+                    if index.is_negative() || index >= bound {
+                        return Err(IndexError::OutOfBounds {
+                            axis: axis_index,
+                            index,
+                            bound,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Row-major strides of the parent array
+        let mut strides = [T::ONE; N];
+        for axis_index in (1..N).rev() {
+            strides[axis_index - 1] = strides[axis_index].wrapping_mul(dim_size[axis_index]);
+        }
+
+        // Advancing an axis wraps all faster varying axes around from their last to their first index.
+        // The changes of the linear index are accumulated with wrapping arithmetic, since they can be negative.
+        let mut carry_deltas = [T::ZERO; N];
+        let mut wrapped = T::ZERO;
+        for &axis_index in self.order.iter().rev() {
+            let axis = &self.axes[axis_index];
+            let stride = strides[axis_index];
+            carry_deltas[axis_index] = wrapped.wrapping_add(axis.step.wrapping_mul(stride));
+            wrapped = wrapped.wrapping_add(axis.first.wrapping_sub(axis.last).wrapping_mul(stride));
+        }
+
+        let mut enumerate = EnumerateLinear {
+            inner: self,
+            strides,
+            carry_deltas,
+            front_linear: T::ZERO,
+            back_linear: T::ZERO,
+        };
+        enumerate.front_linear = enumerate.linear_at(&enumerate.inner.front);
+        enumerate.back_linear = enumerate.linear_at(&enumerate.inner.back);
+        return Ok(enumerate);
+    }
+
+    /**
+    Creates the iterator from unchecked bounds with the given traversal order and reversed axes.
+     */
+    fn from_bounds_with_step_ordered(
+        bounds: [[T; 3]; N],
+        order: PermutedLayout<N>,
+        reversed: [bool; N],
+    ) -> Self {
+        let mut axes = [Axis {
+            first: T::ZERO,
+            last: T::ZERO,
+            count: T::ZERO,
+            step: T::ONE,
+        }; N];
+        let max = axes_unchecked(&bounds, &mut axes);
+        for (axis, reversed) in axes.iter_mut().zip(reversed) {
+            if reversed {
+                *axis = axis.reversed();
+            }
+        }
+
+        return Self {
+            current: T::ZERO,
+            max,
+            front: axes.map(|axis| axis.first),
+            back: axes.map(|axis| axis.last),
+            axes,
+            order: *order.permutation(),
+        };
+    }
+}
+
+impl<const N: usize, T: IndexInt> CartesianIndices<N, T> {
+    /**
+    Returns the cartesian index at the given linear position within the bounds.
+     */
+    fn cart_at(&self, position: T) -> [T; N] {
+        let mut res = [T::ZERO; N];
+        decode_position(
+            position,
+            &self.axes,
+            self.order.iter().rev().copied(),
+            &mut res,
+        );
+        return res;
+    }
+
+    /**
+    Returns the number of remaining elements.
+     */
+    pub(crate) fn remaining(&self) -> T {
+        return self.max - self.current;
+    }
+
+    /**
+    Splits the remaining elements into the first `index` elements and the rest.
+
+    # Panics
+
+    Panics if `index` is larger than the number of remaining elements.
+     */
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = match T::try_from(index) {
+            Ok(index) if index <= self.max - self.current => self.current + index,
+            _ => panic!("split index {index} exceeds the number of remaining elements"),
+        };
+
+        let mut left = self.clone();
+        left.max = mid;
+        if mid > left.current {
+            left.back = left.cart_at(mid - T::ONE);
+        }
+
+        let mut right = self;
+        right.current = mid;
+        if mid < right.max {
+            right.front = right.cart_at(mid);
+        }
+        return (left, right);
+    }
+
+    /**
+    Returns the next element from the front together with the axis which has been advanced afterwards
+    (see [`increment`]).
+     */
+    #[inline]
+    fn step_front(&mut self) -> Option<([T; N], Option<usize>)> {
+        if self.current == self.max {
+            return None;
+        }
+        let res = self.front;
+        self.current += T::ONE;
+        let axis = increment(
+            &mut self.front,
+            &self.axes,
+            self.order.iter().rev().copied(),
+        );
+        return Some((res, axis));
+    }
+
+    /**
+    Returns the next element from the back together with the axis which has been decremented afterwards
+    (see [`decrement`]).
+     */
+    #[inline]
+    fn step_back(&mut self) -> Option<([T; N], Option<usize>)> {
+        if self.current == self.max {
+            return None;
+        }
+        let res = self.back;
+        self.max = self.max - T::ONE;
+        let axis = decrement(&mut self.back, &self.axes, self.order.iter().rev().copied());
+        return Some((res, axis));
+    }
+}
+
+impl<const N: usize, T: IndexInt> Iterator for CartesianIndices<N, T> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.step_front().map(|(res, _)| res);
+    }
+
+    /**
+    Skips `n` elements without iterating over them, i.e. in `O(N)` time for `N` axes. If less than
+    `n + 1` elements remain, the iterator is exhausted and `None` is returned.
+    ```
+    use cart_lin::CartesianIndices;
+
+    let mut cartiter = CartesianIndices::new([2, 3]);
+    assert_eq!(cartiter.next(), Some([0, 0]));
+    assert_eq!(cartiter.nth(2), Some([1, 0]));
+    assert_eq!(cartiter.nth(1), Some([1, 2]));
+    assert_eq!(cartiter.next(), None);
+    ```
+     */
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // If n does not fit into T, it is larger than the number of remaining elements anyway
+        match T::try_from(n) {
+            Ok(n) if n < self.max - self.current => self.current += n,
+            _ => {
+                self.current = self.max;
+                return None;
+            }
+        }
+        self.front = self.cart_at(self.current);
+        return self.next();
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.max - self.current).try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+}
+
+impl<const N: usize, T: IndexInt> DoubleEndedIterator for CartesianIndices<N, T> {
+    /**
+    Returns the next element from the back. The front and the back of the iterator never cross,
+    i.e. each element is yielded exactly once.
+    ```
+    use cart_lin::CartesianIndices;
+
+    let mut cartiter = CartesianIndices::from_bounds([[1, 3], [2, 4]]).unwrap();
+    assert_eq!(cartiter.next_back(), Some([2, 3]));
+    assert_eq!(cartiter.next(), Some([1, 2]));
+    assert_eq!(cartiter.next_back(), Some([2, 2]));
+    assert_eq!(cartiter.next_back(), Some([1, 3]));
+    assert_eq!(cartiter.next(), None);
+    assert_eq!(cartiter.next_back(), None);
+    ```
+     */
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.step_back().map(|(res, _)| res);
+    }
+
+    /**
+    Skips `n` elements from the back without iterating over them, i.e. in `O(N)` time for `N` axes. If
+    less than `n + 1` elements remain, the iterator is exhausted and `None` is returned.
+    ```
+    use cart_lin::CartesianIndices;
+
+    let mut cartiter = CartesianIndices::new([2, 3]);
+    assert_eq!(cartiter.nth_back(1), Some([1, 1]));
+    assert_eq!(cartiter.nth(2), Some([0, 2]));
+    assert_eq!(cartiter.nth_back(0), Some([1, 0]));
+    assert_eq!(cartiter.nth_back(0), None);
+    ```
+     */
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        // If n does not fit into T, it is larger than the number of remaining elements anyway
+        match T::try_from(n) {
+            Ok(n) if n < self.max - self.current => self.max = self.max - n,
+            _ => {
+                self.max = self.current;
+                return None;
+            }
+        }
+        self.back = self.cart_at(self.max - T::ONE);
+        return self.next_back();
+    }
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for CartesianIndices<N, T> {}
+
+/**
+Builder for a [`CartesianIndices`] iterator with a custom traversal order and / or reversed axes.
+
+By default, [`CartesianIndices`] varies the last axis fastest and traverses each axis in ascending order.
+The builder allows choosing which axis varies fastest via a [`PermutedLayout`] (which lists the axes from
+the slowest to the fastest varying one) and traversing individual axes in descending order. The yielded
+cartesian indices keep the original order of the axes, i.e. no index shuffling is required:
+```
+use cart_lin::{CartesianIndicesBuilder, PermutedLayout};
+
+// Column-major traversal of a 2 x 3 matrix, with the second axis descending
+let cartiter = CartesianIndicesBuilder::new([2, 3])
+    .order(PermutedLayout::column_major())
+    .reverse(1)
+    .build()
+    .unwrap();
+assert_eq!(
+    cartiter.collect::<Vec<_>>(),
+    [[0, 2], [1, 2], [0, 1], [1, 1], [0, 0], [1, 0]]
+);
+```
+The resulting iterator supports everything a default [`CartesianIndices`] supports (exact length, random
+access via [`Iterator::nth`] and traversal from the back), with the linear position counted in traversal
+order.
+ */
+#[derive(Debug, Clone)]
+pub struct CartesianIndicesBuilder<const N: usize, T: IndexInt = usize> {
+    bounds: [[T; 3]; N],
+    // Created from dimension sizes, which may be zero (resulting in an empty iterator)
+    from_dim_size: bool,
+    order: PermutedLayout<N>,
+    reversed: [bool; N],
+}
+
+impl<const N: usize, T: IndexInt> CartesianIndicesBuilder<N, T> {
+    /**
+    Creates a new builder using the given dimension sizes, see [`CartesianIndices::new`].
+     */
+    pub fn new(dim_size: [T; N]) -> Self {
+        return Self {
+            bounds: dim_size.map(|dim| [T::ZERO, dim, T::ONE]),
+            from_dim_size: true,
+            order: PermutedLayout::row_major(),
+            reversed: [false; N],
+        };
+    }
+
+    /**
+    Creates a new builder using lower and upper bounds of each dimension, see [`CartesianIndices::from_bounds`].
+     */
+    pub fn from_bounds(bounds: [[T; 2]; N]) -> Self {
+        return Self::from_bounds_with_step(bounds.map(|[start, end]| [start, end, T::ONE]));
+    }
+
+    /**
+    Creates a new builder using the lower bound, the upper bound and the step of each dimension, see
+    [`CartesianIndices::from_bounds_with_step`].
+    ```
+    use cart_lin::CartesianIndicesBuilder;
+
+    // Every second column from right to left
+    let cartiter = CartesianIndicesBuilder::from_bounds_with_step([[0, 2, 1], [0, 5, 2]])
+        .reverse(1)
+        .build()
+        .unwrap();
+    assert_eq!(
+        cartiter.collect::<Vec<_>>(),
+        [[0, 4], [0, 2], [0, 0], [1, 4], [1, 2], [1, 0]]
+    );
+    ```
+     */
+    pub fn from_bounds_with_step(bounds: [[T; 3]; N]) -> Self {
+        return Self {
+            bounds,
+            from_dim_size: false,
+            order: PermutedLayout::row_major(),
+            reversed: [false; N],
+        };
+    }
+
+    /**
+    Sets the traversal order of the axes. The permutation of `order` lists the axes from the slowest
+    to the fastest varying axis, e.g. [`PermutedLayout::column_major`] varies the first axis fastest.
+    Traversing an array in the order in which it is stored in memory is cache-friendly:
+    ```
+    use cart_lin::{CartesianIndicesBuilder, PermutedLayout};
+
+    // Tensor with the logical axes N, C, H, W which is stored as NHWC
+    let nhwc = PermutedLayout::new([0, 2, 3, 1]).unwrap();
+    let dim_size = [1, 3, 2, 2];
+    let cartiter = CartesianIndicesBuilder::new(dim_size).order(nhwc).build().unwrap();
+    for (lin, cart) in cartiter.enumerate() {
+        assert_eq!(nhwc.cart_to_lin(&cart, &dim_size), Ok(lin));
+    }
+    ```
+     */
+    pub fn order(mut self, order: PermutedLayout<N>) -> Self {
+        self.order = order;
+        return self;
+    }
+
+    /**
+    Traverses the given axis in descending order, i.e. from its upper to its lower bound. If the axis
+    has a step, it starts with the largest index which is reached by stepping from the lower bound.
+    Calling this function twice for the same axis restores the ascending order.
+
+    # Panics
+
+    Panics if `axis` is equal to or larger than the number of dimensions `N`.
+     */
+    pub fn reverse(mut self, axis: usize) -> Self {
+        self.reversed[axis] = !self.reversed[axis];
+        return self;
+    }
+
+    /**
+    Checks the bounds (see [`CartesianIndices::new`] respectively [`CartesianIndices::from_bounds_with_step`])
+    and creates the iterator.
+    ```
+    use cart_lin::{CartesianIndicesBuilder, IndexError};
+
+    assert_eq!(
+        CartesianIndicesBuilder::from_bounds([[0, 2], [3, 3]]).reverse(0).build().unwrap_err(),
+        IndexError::InvalidBounds { axis: 1, start: 3, end: 3 }
+    );
+    ```
+     */
+    pub fn build(self) -> Result<CartesianIndices<N, T>, IndexError<T>> {
+        if self.from_dim_size {
+            validate_shape(&self.bounds.map(|[_, dim, _]| dim))?;
+        } else {
+            check_bounds(&self.bounds, &mut [T::ZERO; N])?;
+        }
+        return Ok(CartesianIndices::from_bounds_with_step_ordered(
+            self.bounds,
+            self.order,
+            self.reversed,
+        ));
+    }
+}
+
+/**
+An iterator which yields the cartesian indices of a [`CartesianIndices`] together with their row-major
+linear index into a parent array.
+
+This struct is created by [`CartesianIndices::enumerate_linear`], see its documentation for more.
+ */
+#[derive(Debug, Clone)]
+pub struct EnumerateLinear<const N: usize, T: IndexInt = usize> {
+    inner: CartesianIndices<N, T>,
+    strides: [T; N],
+    // Change of the linear index when advancing the respective axis (including the wraparound of all
+    // faster varying axes)
+    carry_deltas: [T; N],
+    // Linear index of the next element from the front
+    front_linear: T,
+    // Linear index of the next element from the back
+    back_linear: T,
+}
+
+impl<const N: usize, T: IndexInt> EnumerateLinear<N, T> {
+    /**
+    Returns the linear index of the cartesian index within the parent array. Wraps around for cartesian
+    indices outside the parent array (which are never yielded).
+     */
+    fn linear_at(&self, cart: &[T; N]) -> T {
+        let mut res = T::ZERO;
+        for (index, stride) in cart.iter().zip(self.strides.iter()) {
+            res = res.wrapping_add(index.wrapping_mul(*stride));
+        }
+        return res;
+    }
+}
+
+impl<const N: usize, T: IndexInt> Iterator for EnumerateLinear<N, T> {
+    type Item = (T, [T; N]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (cart, axis) = self.inner.step_front()?;
+        let res = (self.front_linear, cart);
+        if let Some(axis) = axis {
+            self.front_linear = self.front_linear.wrapping_add(self.carry_deltas[axis]);
+        }
+        return Some(res);
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let cart = self.inner.nth(n)?;
+        self.front_linear = self.linear_at(&self.inner.front);
+        return Some((self.linear_at(&cart), cart));
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+impl<const N: usize, T: IndexInt> DoubleEndedIterator for EnumerateLinear<N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (cart, axis) = self.inner.step_back()?;
+        let res = (self.back_linear, cart);
+        if let Some(axis) = axis {
+            self.back_linear = self.back_linear.wrapping_sub(self.carry_deltas[axis]);
+        }
+        return Some(res);
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let cart = self.inner.nth_back(n)?;
+        self.back_linear = self.linear_at(&self.inner.back);
+        return Some((self.linear_at(&cart), cart));
+    }
+}
+
+impl<const N: usize, T: IndexInt> ExactSizeIterator for EnumerateLinear<N, T> where
+    CartesianIndices<N, T>: ExactSizeIterator
+{
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for EnumerateLinear<N, T> {}
+
+/**
+Like [`CartesianIndices`], but for a number of dimensions which is only known at runtime (e.g. read from
+a file header).
+
+The cartesian indices are stored on the heap, hence this type requires the `alloc` feature (enabled by
+default via the `std` feature). To avoid an allocation per element, the indices can be borrowed from the
+iterator via [`CartesianIndicesDyn::next_slice`] or copied into a caller-provided buffer via
+[`CartesianIndicesDyn::next_into`]:
+```
+use cart_lin::CartesianIndicesDyn;
+
+// Number of dimensions read from e.g. a file header
+let dim_size = vec![2, 3];
+let mut cartiter = CartesianIndicesDyn::new(&dim_size);
+assert_eq!(cartiter.next_slice(), Some([0, 0].as_slice()));
+assert_eq!(cartiter.next_slice(), Some([0, 1].as_slice()));
+
+let mut indices = vec![0; cartiter.ndim()];
+while cartiter.next_into(&mut indices) {
+    // Do something with indices
+}
+assert_eq!(indices, [1, 2]);
+```
+Additionally, [`CartesianIndicesDyn`] implements [`Iterator`] with owned `Vec` items, which allocates for
+each element:
+```
+use cart_lin::CartesianIndicesDyn;
+
+let all: Vec<Vec<usize>> = CartesianIndicesDyn::new(&[2, 2]).collect();
+assert_eq!(all, [[0, 0], [0, 1], [1, 0], [1, 1]]);
+```
+ */
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CartesianIndicesDyn<T: IndexInt = usize> {
+    // Linear position of the next element
+    current: T,
+    // Total number of elements
+    max: T,
+    // Cartesian index of the next element
+    front: Box<[T]>,
+    // Cartesian index of the element returned by the last call of `next_slice`
+    item: Box<[T]>,
+    axes: Box<[Axis<T>]>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IndexInt> CartesianIndicesDyn<T> {
+    /**
+    Creates a new [`CartesianIndicesDyn`] iterator using the given dimension sizes.
+    ```
+    use cart_lin::CartesianIndicesDyn;
+
+    let mut cartiter = CartesianIndicesDyn::new(&[1, 2]);
+    assert_eq!(cartiter.next_slice(), Some([0, 0].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([0, 1].as_slice()));
+    assert_eq!(cartiter.next_slice(), None);
+    ```
+
+    # Panics
+
+    Panics if a dimension size is negative or if the total number of elements overflows the index type
+    (see [`validate_shape`]). Use [`CartesianIndicesDyn::try_new`] for untrusted input.
+     */
+    pub fn new(dim_size: &[T]) -> Self {
+        match Self::try_new(dim_size) {
+            Ok(cartiter) => return cartiter,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /**
+    Like [`CartesianIndicesDyn::new`], but returns an error instead of panicking if a dimension size is
+    negative or if the shape overflows (see [`validate_shape`]).
+    ```
+    use cart_lin::{CartesianIndicesDyn, IndexError};
+
+    assert!(CartesianIndicesDyn::try_new(&[2, 3]).is_ok());
+    assert_eq!(
+        CartesianIndicesDyn::try_new(&[2, usize::MAX]).unwrap_err(),
+        IndexError::Overflow
+    );
+    ```
+     */
+    pub fn try_new(dim_size: &[T]) -> Result<Self, IndexError<T>> {
+        validate_shape(dim_size)?;
+        let bounds: Vec<[T; 2]> = dim_size.iter().map(|dim| [T::ZERO, *dim]).collect();
+        return Ok(Self::from_bounds_unchecked(&bounds));
+    }
+
+    /**
+    Creates a new [`CartesianIndicesDyn`] using lower and upper bounds of each dimension.
+    See [`CartesianIndices::from_bounds`] for the requirements on the bounds.
+    ```
+    use cart_lin::{CartesianIndicesDyn, IndexError};
+
+    let mut cartiter = CartesianIndicesDyn::from_bounds(&[[1, 3], [2, 4]]).unwrap();
+    assert_eq!(cartiter.next_slice(), Some([1, 2].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([1, 3].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([2, 2].as_slice()));
+
+    assert_eq!(
+        CartesianIndicesDyn::from_bounds(&[[1, 3], [4, 4]]).unwrap_err(),
+        IndexError::InvalidBounds { axis: 1, start: 4, end: 4 }
+    );
+    ```
+     */
+    pub fn from_bounds(bounds: &[[T; 2]]) -> Result<Self, IndexError<T>> {
+        let bounds: Vec<[T; 3]> = bounds
+            .iter()
+            .map(|&[start, end]| [start, end, T::ONE])
+            .collect();
+        return Self::from_bounds_with_step(&bounds);
+    }
+
+    /**
+    Like [`CartesianIndicesDyn::from_bounds`], but without the checks. See [`CartesianIndices::from_bounds_unchecked`].
+     */
+    pub fn from_bounds_unchecked(bounds: &[[T; 2]]) -> Self {
+        let bounds: Vec<[T; 3]> = bounds
+            .iter()
+            .map(|&[start, end]| [start, end, T::ONE])
+            .collect();
+        return Self::from_bounds_with_step_unchecked(&bounds);
+    }
+
+    /**
+    Creates a new [`CartesianIndicesDyn`] using the lower bound, the upper bound and the step of each dimension.
+    See [`CartesianIndices::from_bounds_with_step`] for the requirements on the bounds and steps.
+    ```
+    use cart_lin::CartesianIndicesDyn;
+
+    let all: Vec<Vec<usize>> = CartesianIndicesDyn::from_bounds_with_step(&[[1, 4, 2], [0, 2, 1]])
+        .unwrap()
+        .collect();
+    assert_eq!(all, [[1, 0], [1, 1], [3, 0], [3, 1]]);
+    ```
+     */
+    pub fn from_bounds_with_step(bounds: &[[T; 3]]) -> Result<Self, IndexError<T>> {
+        check_bounds(bounds, &mut vec![T::ZERO; bounds.len()])?;
+        return Ok(Self::from_bounds_with_step_unchecked(bounds));
+    }
+
+    /**
+    Like [`CartesianIndicesDyn::from_bounds_with_step`], but without the checks. See
+    [`CartesianIndices::from_bounds_with_step_unchecked`].
+     */
+    pub fn from_bounds_with_step_unchecked(bounds: &[[T; 3]]) -> Self {
+        let mut axes = vec![
+            Axis {
+                first: T::ZERO,
+                last: T::ZERO,
+                count: T::ZERO,
+                step: T::ONE,
+            };
+            bounds.len()
+        ];
+        let max = axes_unchecked(bounds, &mut axes);
+        let front: Box<[T]> = axes.iter().map(|axis| axis.first).collect();
+        return Self {
+            current: T::ZERO,
+            max,
+            item: front.clone(),
+            front,
+            axes: axes.into_boxed_slice(),
+        };
+    }
+
+    /**
+    Returns the number of dimensions.
+     */
+    pub fn ndim(&self) -> usize {
+        return self.axes.len();
+    }
+
+    /**
+    Advances the iterator and returns the next cartesian index as a slice borrowed from the iterator.
+    This is the allocation-free equivalent of [`Iterator::next`].
+    ```
+    use cart_lin::CartesianIndicesDyn;
+
+    let mut cartiter = CartesianIndicesDyn::new(&[2, 3, 4]);
+    let mut count = 0;
+    while let Some(indices) = cartiter.next_slice() {
+        assert_eq!(indices.len(), 3);
+        count += 1;
+    }
+    assert_eq!(count, 24);
+    ```
+     */
+    pub fn next_slice(&mut self) -> Option<&[T]> {
+        if self.current == self.max {
+            return None;
+        }
+        self.item.copy_from_slice(&self.front);
+        self.current += T::ONE;
+        increment(&mut self.front, &self.axes, (0..self.axes.len()).rev());
+        return Some(&self.item);
+    }
+
+    /**
+    Advances the iterator and writes the next cartesian index into `cart_indices`. Returns `false`
+    (and does not change `cart_indices`) if the iterator is exhausted.
+    ```
+    use cart_lin::CartesianIndicesDyn;
+
+    let mut cartiter = CartesianIndicesDyn::new(&[2, 2]);
+    let mut indices = [0; 2];
+    assert!(cartiter.next_into(&mut indices));
+    assert_eq!(indices, [0, 0]);
+    assert!(cartiter.nth(1).is_some());
+    assert!(cartiter.next_into(&mut indices));
+    assert_eq!(indices, [1, 1]);
+    assert!(!cartiter.next_into(&mut indices));
+    ```
+
+    # Panics
+
+    Panics if the length of `cart_indices` is not equal to the number of dimensions.
+     */
+    pub fn next_into(&mut self, cart_indices: &mut [T]) -> bool {
+        match self.next_slice() {
+            Some(indices) => {
+                cart_indices.copy_from_slice(indices);
+                return true;
+            }
+            None => return false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IndexInt> Iterator for CartesianIndicesDyn<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.next_slice().map(|indices| indices.to_vec());
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // If n does not fit into T, it is larger than the number of remaining elements anyway
+        match T::try_from(n) {
+            Ok(n) if n < self.max - self.current => self.current += n,
+            _ => {
+                self.current = self.max;
+                return None;
+            }
+        }
+        decode_position(
+            self.current,
+            &self.axes,
+            (0..self.axes.len()).rev(),
+            &mut self.front,
+        );
+        return self.next();
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.max - self.current).try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IndexInt> FusedIterator for CartesianIndicesDyn<T> {}
+
+/*
+Like std's `Range`, `ExactSizeIterator` is only implemented for index types whose number of
+elements is guaranteed to fit into an `usize` on the current target.
+ */
+macro_rules! impl_exact_size {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> ExactSizeIterator for CartesianIndices<N, $t> {}
+
+            #[cfg(feature = "alloc")]
+            impl ExactSizeIterator for CartesianIndicesDyn<$t> {}
+        )*
+    };
+}
+
+impl_exact_size!(u8, u16, usize, i8, i16, isize);
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_exact_size!(u32, i32);
+
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(u64, i64);
@@ -0,0 +1,240 @@
+/*!
+The error type returned by all checked functions of this crate.
+ */
+
+use core::fmt;
+
+use crate::index_int::IndexInt;
+
+/**
+Error returned by the checked conversion functions and constructors of this crate.
+
+Each variant carries the information needed to locate the invalid input, e.g. which axis
+of a cartesian index is out of bounds. The type parameter `T` is the integer type of the
+indices (see [`IndexInt`]):
+```
+use cart_lin::{IndexError, cart_to_lin};
+
+let err = cart_to_lin(&[1, 5], &[2, 5]).unwrap_err();
+assert_eq!(err, IndexError::OutOfBounds { axis: 1, index: 5, bound: 5 });
+assert_eq!(err.to_string(), "index 5 is out of bounds for axis 1 with size 5");
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexError<T: IndexInt = usize> {
+    /// The number of dimensions of an input does not match the expected number of dimensions.
+    DimensionMismatch {
+        /// Expected number of dimensions.
+        expected: usize,
+        /// Actual number of dimensions.
+        got: usize,
+    },
+    /// The index along an axis is negative or equal to or larger than the size of this axis.
+    OutOfBounds {
+        /// Axis of the invalid index.
+        axis: usize,
+        /// The invalid index.
+        index: T,
+        /// Size of the axis (exclusive upper bound).
+        bound: T,
+    },
+    /// The linear index is negative or equal to or larger than the total number of elements.
+    LinearOutOfRange {
+        /// The invalid linear index.
+        index: T,
+        /// Total number of elements (exclusive upper bound).
+        len: T,
+    },
+    /// The lower bound of an axis is not smaller than its upper bound.
+    InvalidBounds {
+        /// Axis of the invalid bounds.
+        axis: usize,
+        /// Lower bound (inclusive).
+        start: T,
+        /// Upper bound (exclusive).
+        end: T,
+    },
+    /// The step between consecutive indices of an axis is not positive.
+    InvalidStep {
+        /// Axis of the invalid step.
+        axis: usize,
+        /// The invalid step.
+        step: T,
+    },
+    /// An axis is either out of range or listed multiple times in a permutation.
+    InvalidPermutation {
+        /// The invalid axis.
+        axis: usize,
+    },
+    /// The offset calculated for a cartesian index lies before the start of the buffer.
+    NegativeOffset,
+    /// No element of the layout is located at the offset.
+    UnmappedOffset {
+        /// The offset which does not belong to any element.
+        offset: T,
+    },
+    /// The element is not stored by a packed or banded layout (e.g. it lies outside of the stored triangle).
+    NotStored {
+        /// Row index of the element.
+        row: T,
+        /// Column index of the element.
+        column: T,
+    },
+    /// The cartesian index is not sorted in ascending order, although a representative of a symmetric tensor
+    /// is required.
+    NotSorted {
+        /// The first axis whose index is smaller than the index of the previous axis.
+        axis: usize,
+    },
+    /// The size of a dimension is negative (only possible for signed index types).
+    NegativeDimension {
+        /// Axis of the invalid dimension size.
+        axis: usize,
+        /// The invalid dimension size.
+        size: T,
+    },
+    /// The total number of elements, a stride or an offset does not fit into the index type.
+    Overflow,
+}
+
+impl<T: IndexInt> fmt::Display for IndexError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::DimensionMismatch { expected, got } => {
+                write!(f, "expected {expected} dimensions, got {got}")
+            }
+            IndexError::OutOfBounds { axis, index, bound } => {
+                write!(
+                    f,
+                    "index {index} is out of bounds for axis {axis} with size {bound}"
+                )
+            }
+            IndexError::LinearOutOfRange { index, len } => {
+                write!(f, "linear index {index} is out of range for {len} elements")
+            }
+            IndexError::InvalidBounds { axis, start, end } => {
+                write!(
+                    f,
+                    "lower bound {start} of axis {axis} is not smaller than upper bound {end}"
+                )
+            }
+            IndexError::InvalidStep { axis, step } => {
+                write!(f, "step {step} of axis {axis} is not positive")
+            }
+            IndexError::InvalidPermutation { axis } => {
+                write!(
+                    f,
+                    "axis {axis} is out of range or listed multiple times in the permutation"
+                )
+            }
+            IndexError::NegativeOffset => {
+                write!(f, "offset lies before the start of the buffer")
+            }
+            IndexError::UnmappedOffset { offset } => {
+                write!(f, "no element is located at offset {offset}")
+            }
+            IndexError::NotStored { row, column } => {
+                write!(f, "element ({row}, {column}) is not stored by the layout")
+            }
+            IndexError::NotSorted { axis } => {
+                write!(
+                    f,
+                    "index of axis {axis} is smaller than the index of the previous axis"
+                )
+            }
+            IndexError::NegativeDimension { axis, size } => {
+                write!(f, "size {size} of axis {axis} is negative")
+            }
+            IndexError::Overflow => {
+                write!(
+                    f,
+                    "number of elements, stride or offset overflows the index type"
+                )
+            }
+        }
+    }
+}
+
+impl<T: IndexInt> core::error::Error for IndexError<T> {}
+
+/**
+Check whether a shape (size of each dimension) can be used for conversions between linear and cartesian
+indices and return its total number of elements.
+
+A shape is valid if none of its dimension sizes is negative and if the product of all its nonzero dimension
+sizes fits into the index type `T` (see [`IndexInt`]). This guarantees that neither the total number of elements
+nor any stride (in any memory order) overflows. Otherwise, [`IndexError::NegativeDimension`] respectively
+[`IndexError::Overflow`] is returned. All checked functions of this crate perform this validation
+internally; calling it once when reading e.g. the dimensions from an untrusted file header allows using the
+unchecked functions afterwards.
+```
+use cart_lin::{IndexError, validate_shape};
+
+assert_eq!(validate_shape(&[2, 3, 4]), Ok(24));
+assert_eq!(validate_shape(&[2, 0, 4]), Ok(0));
+assert_eq!(validate_shape(&[usize::MAX, 2]), Err(IndexError::Overflow));
+
+// The shape does not contain any elements, but the stride of the first axis still overflows
+assert_eq!(validate_shape(&[0, usize::MAX, 2]), Err(IndexError::Overflow));
+
+// Overflow checks respect the index type
+assert_eq!(validate_shape::<u8>(&[16, 15]), Ok(240));
+assert_eq!(validate_shape::<u8>(&[16, 16]), Err(IndexError::Overflow));
+```
+ */
+pub fn validate_shape<T: IndexInt>(dim_size: &[T]) -> Result<T, IndexError<T>> {
+    let mut len = T::ONE;
+    let mut empty = false;
+    for (axis, bound) in dim_size.iter().enumerate() {
+        if bound.is_negative() {
+            return Err(IndexError::NegativeDimension { axis, size: *bound });
+        } else if *bound == T::ZERO {
+            empty = true;
+        } else {
+            len = len.checked_mul(*bound).ok_or(IndexError::Overflow)?;
+        }
+    }
+    if empty {
+        return Ok(T::ZERO);
+    } else {
+        return Ok(len);
+    }
+}
+
+/**
+Check whether the given indices are valid. This is the case if the length of `indices`
+is equal to the dimensionality of the data `N`, and if all individual axes indices are in bounds
+(i.e. not negative and smaller than the dimension size).
+*/
+pub(crate) fn check_indices<T: IndexInt>(
+    indices: &[T],
+    dim_size: &[T],
+) -> Result<(), IndexError<T>> {
+    if indices.len() != dim_size.len() {
+        return Err(IndexError::DimensionMismatch {
+            expected: dim_size.len(),
+            got: indices.len(),
+        });
+    }
+    for (axis, (cart_index, bound)) in indices.iter().zip(dim_size.iter()).enumerate() {
+        if cart_index.is_negative() || *cart_index >= *bound {
+            return Err(IndexError::OutOfBounds {
+                axis,
+                index: *cart_index,
+                bound: *bound,
+            });
+        }
+    }
+    return Ok(());
+}
+
+/**
+Check whether the linear index is not negative and smaller than the total number of elements `len`.
+ */
+pub(crate) fn check_linear<T: IndexInt>(index: T, len: T) -> Result<(), IndexError<T>> {
+    if index.is_negative() || index >= len {
+        return Err(IndexError::LinearOutOfRange { index, len });
+    }
+    return Ok(());
+}
@@ -0,0 +1,265 @@
+/*!
+Division-free conversion from linear to cartesian indices using precomputed reciprocals.
+ */
+
+use crate::error::{IndexError, check_linear};
+use crate::order::{Order, RowMajor};
+use crate::shape::Shape;
+
+/**
+An integer divisor with a precomputed "magic number", which replaces the division by a
+multiplication, an addition and two shifts.
+
+This is the "round-up" method from T. Granlund and P. Montgomery, "Division by Invariant Integers
+using Multiplication" (1994), figure 4.1, which is exact for all 64 bit dividends and divisors.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Divisor {
+    divisor: u64,
+    multiplier: u64,
+    shift1: u32,
+    shift2: u32,
+}
+
+impl Divisor {
+    /**
+    Precomputes the magic number for `divisor`. A divisor of zero is treated as a divisor of one, such that
+    the remainder is always zero (see [`FastShape::to_cart_unchecked`]).
+     */
+    fn new(divisor: u64) -> Self {
+        let divisor = divisor.max(1);
+
+        // l = ceil(log2(divisor))
+        let l = u64::BITS - (divisor - 1).leading_zeros();
+
+        // m = floor(2^64 * (2^l - d) / d) + 1, which always fits into 64 bits
+        let multiplier = ((((1u128 << l) - divisor as u128) << 64) / divisor as u128 + 1) as u64;
+
+        return Self {
+            divisor,
+            multiplier,
+            shift1: l.min(1),
+            shift2: l.saturating_sub(1),
+        };
+    }
+
+    /**
+    Returns `(n / divisor, n % divisor)`.
+     */
+    #[inline]
+    fn div_rem(&self, n: u64) -> (u64, u64) {
+        let t1 = ((self.multiplier as u128 * n as u128) >> 64) as u64;
+        let quotient = (t1 + ((n - t1) >> self.shift1)) >> self.shift2;
+        return (quotient, n - quotient * self.divisor);
+    }
+}
+
+/**
+Like [`Shape`], but with a division-free conversion from linear to cartesian indices.
+
+Converting a linear index into a cartesian index requires an integer division and a modulo operation
+per axis (see [`lin_to_cart_dyn_unchecked`](crate::lin_to_cart_dyn_unchecked)). Since hardware integer
+division is slow, a [`FastShape`] precomputes a reciprocal ("magic number") for each dimension size on
+construction and decodes linear indices using only multiplications, additions and shifts. This pays off
+if many linear indices are converted with the same shape.
+
+The results are identical to those of [`Shape`] (and therefore to those of [`lin_to_cart`](crate::lin_to_cart))
+for all valid linear indices. For shapes without axes of size zero, this includes the out-of-bounds behaviour
+of [`FastShape::to_cart_unchecked`].
+```
+use cart_lin::FastShape;
+
+let shape = FastShape::new([2, 3, 4]);
+assert_eq!(shape.len(), 24);
+assert_eq!(shape.to_cart(23).unwrap(), [1, 2, 3]);
+assert_eq!(shape.to_lin(&[1, 2, 3]).unwrap(), 23);
+assert!(shape.to_cart(24).is_err()); // Out of bounds
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FastShape<const N: usize, O: Order = RowMajor> {
+    shape: Shape<N, O>,
+    divisors: [Divisor; N],
+    // Axes sorted from the fastest to the slowest varying one
+    axes: [usize; N],
+}
+
+impl<const N: usize> FastShape<N> {
+    /**
+    Creates a new [`FastShape`] in row-major order from the given dimension sizes.
+    ```
+    use cart_lin::FastShape;
+
+    let shape = FastShape::new([2, 3]);
+    assert_eq!(shape.to_cart(4).unwrap(), [1, 1]);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`FastShape::try_new`] for untrusted input.
+     */
+    pub fn new(dim_size: [usize; N]) -> Self {
+        return Self::from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Like [`FastShape::new`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{FastShape, IndexError};
+
+    assert_eq!(FastShape::try_new([2, 3]).unwrap().len(), 6);
+    assert_eq!(FastShape::try_new([usize::MAX, 2]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_new(dim_size: [usize; N]) -> Result<Self, IndexError> {
+        return Self::try_from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Creates a new [`FastShape`] in the memory order `O` from the given dimension sizes.
+    ```
+    use cart_lin::{ColumnMajor, FastShape};
+
+    let shape = FastShape::from_order::<ColumnMajor>([2, 3]);
+    assert_eq!(shape.to_cart(1).unwrap(), [1, 0]);
+    assert_eq!(shape.to_cart(4).unwrap(), [0, 2]);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`FastShape::try_from_order`] for untrusted input.
+     */
+    pub fn from_order<O: Order>(dim_size: [usize; N]) -> FastShape<N, O> {
+        match Self::try_from_order::<O>(dim_size) {
+            Ok(shape) => return shape,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /**
+    Like [`FastShape::from_order`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{ColumnMajor, FastShape, IndexError};
+
+    assert!(FastShape::try_from_order::<ColumnMajor>([2, 3]).is_ok());
+    assert_eq!(FastShape::try_from_order::<ColumnMajor>([2, usize::MAX]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_from_order<O: Order>(dim_size: [usize; N]) -> Result<FastShape<N, O>, IndexError> {
+        let shape = Shape::try_from_order::<O>(dim_size)?;
+
+        let mut axes = [0; N];
+        for (i, axis) in axes.iter_mut().enumerate() {
+            *axis = i;
+        }
+        // Axes of size zero are sorted (and decoded) like axes of size one, since their strides would
+        // not reflect the memory order. Equal strides only occur next to axes of size one, whose relative
+        // order does not matter.
+        let mut strides = [0; N];
+        O::strides(&dim_size.map(|dim| dim.max(1)), &mut strides);
+        axes.sort_unstable_by_key(|axis| strides[*axis]);
+
+        return Ok(FastShape {
+            shape,
+            divisors: dim_size.map(|dim| Divisor::new(dim as u64)),
+            axes,
+        });
+    }
+}
+
+impl<const N: usize, O: Order> FastShape<N, O> {
+    /**
+    Returns the underlying [`Shape`].
+     */
+    pub fn shape(&self) -> &Shape<N, O> {
+        return &self.shape;
+    }
+
+    /**
+    Returns the size of each dimension.
+     */
+    pub fn dim_size(&self) -> &[usize; N] {
+        return self.shape.dim_size();
+    }
+
+    /**
+    Returns the total number of elements.
+     */
+    pub fn len(&self) -> usize {
+        return self.shape.len();
+    }
+
+    /**
+    Returns `true` if the shape does not contain any elements (i.e. at least one dimension has size zero).
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.shape.is_empty();
+    }
+
+    /**
+    Convert a cartesian index into a linear index. See [`Shape::to_lin`].
+     */
+    pub fn to_lin(&self, indices: &[usize; N]) -> Result<usize, IndexError> {
+        return self.shape.to_lin(indices);
+    }
+
+    /**
+    Like [`FastShape::to_lin`], but without the checks. See [`Shape::to_lin_unchecked`].
+     */
+    pub fn to_lin_unchecked(&self, indices: &[usize; N]) -> usize {
+        return self.shape.to_lin_unchecked(indices);
+    }
+
+    /**
+    Convert a linear index to a cartesian index without using integer divisions.
+
+    If the linear index is out of bounds (= equal to or larger than [`FastShape::len`]), this function
+    returns an error.
+    ```
+    use cart_lin::FastShape;
+
+    let shape = FastShape::new([7, 3]);
+    assert_eq!(shape.to_cart(20).unwrap(), [6, 2]);
+    assert!(shape.to_cart(21).is_err()); // Out of bounds
+    ```
+     */
+    pub fn to_cart(&self, index: usize) -> Result<[usize; N], IndexError> {
+        check_linear(index, self.shape.len())?;
+        return Ok(self.to_cart_unchecked(index));
+    }
+
+    /**
+    Like [`FastShape::to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::FastShape;
+
+    let shape = FastShape::new([2, 3]);
+    assert_eq!(shape.to_cart_unchecked(4), [1, 1]);
+    assert_eq!(shape.to_cart_unchecked(6), [0, 0]); // Nonsensical value (wrapping around)
+    ```
+    Unlike [`Shape::to_cart_unchecked`], this function does not panic for shapes containing an axis of size
+    zero. Such axes are treated like axes of size one, i.e. their index is always zero:
+    ```
+    use cart_lin::FastShape;
+
+    let shape = FastShape::new([2, 0, 3]);
+    assert_eq!(shape.to_cart_unchecked(4), [1, 0, 1]);
+    ```
+     */
+    #[inline]
+    pub fn to_cart_unchecked(&self, index: usize) -> [usize; N] {
+        let mut indices = [0; N];
+        let mut index = index as u64;
+        for axis in self.axes.iter() {
+            let (quotient, remainder) = self.divisors[*axis].div_rem(index);
+            indices[*axis] = remainder as usize;
+            index = quotient;
+        }
+        return indices;
+    }
+}
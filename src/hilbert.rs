@@ -0,0 +1,390 @@
+/*!
+Conversion between cartesian indices and distances along the Hilbert curve.
+
+The implementation is based on J. Skilling, "Programming the Hilbert curve", AIP Conference Proceedings 707
+(2004), which works for any number of dimensions.
+ */
+
+use core::iter::FusedIterator;
+
+use crate::cartesian_indices::CartesianIndices;
+use crate::error::{IndexError, check_indices, check_linear, validate_shape};
+use crate::index_int::IndexInt;
+use crate::morton::axis_bits;
+
+/**
+Returns the side length `2^order` of the hypercube covered by a Hilbert curve of the given order and the
+number of cells `2^(N * order)` of the hypercube. Returns an error if the number of cells does not fit
+into `T`.
+ */
+fn hilbert_side_and_len<T: IndexInt, const N: usize>(order: u32) -> Result<(T, T), IndexError<T>> {
+    let two = T::ONE + T::ONE;
+    let mut side = T::ONE;
+    let mut len = T::ONE;
+    for _ in 0..order {
+        side = side.checked_mul(two).ok_or(IndexError::Overflow)?;
+        for _ in 0..N {
+            len = len.checked_mul(two).ok_or(IndexError::Overflow)?;
+        }
+    }
+    return Ok((side, len));
+}
+
+/**
+Converts cartesian indices into the "transposed" Hilbert distance (see Skilling): Bit `level` of the
+transposed value of axis `i` is bit `level * N + N - 1 - i` of the distance.
+ */
+fn axes_to_transpose<T: IndexInt, const N: usize>(x: &mut [T; N], order: u32) {
+    let Some((first, rest)) = x.split_first_mut() else {
+        return;
+    };
+
+    // Inverse undo of the excess work
+    for level in (1..order).rev() {
+        let q = T::ONE << level;
+        let p = q.wrapping_sub(T::ONE);
+        if *first & q != T::ZERO {
+            *first = *first ^ p;
+        }
+        for xi in rest.iter_mut() {
+            if *xi & q != T::ZERO {
+                *first = *first ^ p;
+            } else {
+                let t = (*first ^ *xi) & p;
+                *first = *first ^ t;
+                *xi = *xi ^ t;
+            }
+        }
+    }
+
+    // Gray encode
+    let mut previous = *first;
+    for xi in rest.iter_mut() {
+        *xi = *xi ^ previous;
+        previous = *xi;
+    }
+    let mut t = T::ZERO;
+    for level in (1..order).rev() {
+        let q = T::ONE << level;
+        if previous & q != T::ZERO {
+            t = t ^ q.wrapping_sub(T::ONE);
+        }
+    }
+    for xi in x.iter_mut() {
+        *xi = *xi ^ t;
+    }
+}
+
+/**
+The inverse of [`axes_to_transpose`].
+ */
+fn transpose_to_axes<T: IndexInt, const N: usize>(x: &mut [T; N], order: u32) {
+    if N == 0 {
+        return;
+    }
+
+    // Gray decode
+    let t = x[N - 1] >> 1;
+    for i in (1..N).rev() {
+        x[i] = x[i] ^ x[i - 1];
+    }
+    x[0] = x[0] ^ t;
+
+    // Undo the excess work
+    for level in 1..order {
+        let q = T::ONE << level;
+        let p = q.wrapping_sub(T::ONE);
+        for i in (0..N).rev() {
+            if x[i] & q != T::ZERO {
+                x[0] = x[0] ^ p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] = x[0] ^ t;
+                x[i] = x[i] ^ t;
+            }
+        }
+    }
+}
+
+/**
+Convert a cartesian index into the distance along the Hilbert curve of the given order.
+
+A Hilbert curve of order `order` visits all cells of an `N`-dimensional hypercube with the side length
+`2^order` (i.e. each index must be smaller than `2^order`), starting at the origin. Consecutive
+distances always belong to neighboring cells, which gives a better locality than the Z-order curve (see
+[`cart_to_morton`](crate::cart_to_morton)). For shapes which are not hypercubes of this size, see
+[`HilbertIndices`].
+
+If any of the cartesian indices are out of bounds, this function returns an [`IndexError`]. If the number
+of cells `2^(N * order)` does not fit into the index type, [`IndexError::Overflow`] is returned.
+```
+use cart_lin::{IndexError, cart_to_hilbert};
+
+// Hilbert curve of order 2:
+// [ 0  3  4  5]
+// [ 1  2  7  6]
+// [14 13  8  9]
+// [15 12 11 10]
+assert_eq!(cart_to_hilbert(&[0, 0], 2).unwrap(), 0);
+assert_eq!(cart_to_hilbert(&[1, 0], 2).unwrap(), 1);
+assert_eq!(cart_to_hilbert(&[1, 2], 2).unwrap(), 7);
+assert_eq!(cart_to_hilbert(&[3, 3], 2).unwrap(), 10);
+
+assert_eq!(
+    cart_to_hilbert(&[0, 4], 2),
+    Err(IndexError::OutOfBounds { axis: 1, index: 4, bound: 4 })
+);
+assert_eq!(cart_to_hilbert::<u8, 2>(&[0, 0], 4), Err(IndexError::Overflow));
+```
+ */
+pub fn cart_to_hilbert<T: IndexInt, const N: usize>(
+    indices: &[T; N],
+    order: u32,
+) -> Result<T, IndexError<T>> {
+    let (side, _) = hilbert_side_and_len::<T, N>(order)?;
+    check_indices(indices, &[side; N])?;
+    return Ok(cart_to_hilbert_unchecked(indices, order));
+}
+
+/**
+Like [`cart_to_hilbert`], but without the checks.
+
+Despite the name, this function itself is safe. However, the distance received from this function might
+be invalid. Using such an invalid distance may cause an out-of-bounds read.
+```
+use cart_lin::cart_to_hilbert_unchecked;
+
+assert_eq!(cart_to_hilbert_unchecked(&[1, 2], 2), 7);
+```
+ */
+pub fn cart_to_hilbert_unchecked<T: IndexInt, const N: usize>(indices: &[T; N], order: u32) -> T {
+    let order = order.min(T::BITS);
+    let mut x = *indices;
+    axes_to_transpose(&mut x, order);
+
+    // Interleave the transposed bits, with the first axis being the most significant one of each level
+    let mut distance = T::ZERO;
+    for level in (0..order).rev() {
+        for xi in x.iter() {
+            distance = (distance << 1) | ((*xi >> level) & T::ONE);
+        }
+    }
+    return distance;
+}
+
+/**
+Convert a distance along the Hilbert curve of the given order into a cartesian index, the inverse of
+[`cart_to_hilbert`].
+
+If the distance is equal to or larger than the number of cells `2^(N * order)`, an
+[`IndexError::LinearOutOfRange`] is returned. If the number of cells does not fit into the index type,
+[`IndexError::Overflow`] is returned.
+```
+use cart_lin::{IndexError, hilbert_to_cart};
+
+assert_eq!(hilbert_to_cart(7, 2).unwrap(), [1, 2]);
+assert_eq!(hilbert_to_cart(12, 2).unwrap(), [3, 1]);
+
+// Three dimensions
+assert_eq!(hilbert_to_cart(7, 1).unwrap(), [1, 0, 0]);
+
+assert_eq!(
+    hilbert_to_cart::<usize, 2>(16, 2),
+    Err(IndexError::LinearOutOfRange { index: 16, len: 16 })
+);
+```
+ */
+pub fn hilbert_to_cart<T: IndexInt, const N: usize>(
+    distance: T,
+    order: u32,
+) -> Result<[T; N], IndexError<T>> {
+    let (_, len) = hilbert_side_and_len::<T, N>(order)?;
+    check_linear(distance, len)?;
+    return Ok(hilbert_to_cart_unchecked(distance, order));
+}
+
+/**
+Like [`hilbert_to_cart`], but without the checks.
+
+Despite the name, this function itself is safe. However, the index received from this function might be
+invalid. Using such an invalid index may cause an out-of-bounds read.
+```
+use cart_lin::hilbert_to_cart_unchecked;
+
+assert_eq!(hilbert_to_cart_unchecked(7, 2), [1, 2]);
+```
+ */
+pub fn hilbert_to_cart_unchecked<T: IndexInt, const N: usize>(distance: T, order: u32) -> [T; N] {
+    let order = order.min(T::BITS);
+    let mut x = [T::ZERO; N];
+    let mut position = 0;
+    for level in 0..order {
+        for xi in x.iter_mut().rev() {
+            if position < T::BITS {
+                *xi = *xi | (((distance >> position) & T::ONE) << level);
+                position += 1;
+            }
+        }
+    }
+    transpose_to_axes(&mut x, order);
+    return x;
+}
+
+/**
+An iterator over the cartesian indices of a box along the Hilbert curve.
+
+The box is covered by the Hilbert curve of the smallest order whose hypercube is large enough for the
+extent of each axis, with the origin of the curve at the lower bounds of the box (see
+[`cart_to_hilbert`]). The cartesian indices are yielded in ascending order of their distance along this
+curve. Distances outside of the box are skipped block-wise: each block of distances whose sub-cube lies
+outside of the box is skipped at once. For boxes which are not hypercubes, consecutive elements are not
+necessarily neighbors anymore, but they are still close to each other.
+```
+use cart_lin::HilbertIndices;
+
+let mut cartiter = HilbertIndices::new([4, 4]).unwrap();
+assert_eq!(cartiter.len(), 16);
+assert_eq!(cartiter.next(), Some([0, 0]));
+assert_eq!(cartiter.next(), Some([1, 0]));
+assert_eq!(cartiter.next(), Some([1, 1]));
+assert_eq!(cartiter.next(), Some([0, 1]));
+assert_eq!(cartiter.next(), Some([0, 2]));
+
+// A 3 x 2 box starting at [1, 5]
+let cartiter = HilbertIndices::from_bounds([[1, 4], [5, 7]]).unwrap();
+assert_eq!(
+    cartiter.collect::<Vec<_>>(),
+    [[1, 5], [2, 5], [2, 6], [1, 6], [3, 6], [3, 5]]
+);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct HilbertIndices<const N: usize, T: IndexInt = usize> {
+    // Next distance to be examined
+    distance: T,
+    order: u32,
+    start: [T; N],
+    extent: [T; N],
+    // Number of remaining cartesian indices
+    remaining: T,
+}
+
+impl<const N: usize, T: IndexInt> HilbertIndices<N, T> {
+    /**
+    Creates a new [`HilbertIndices`] iterator over an array with the given dimension sizes.
+
+    If the shape is invalid (see [`validate_shape`](crate::validate_shape)) or if the number of cells of
+    the Hilbert curve covering the shape does not fit into the index type, an [`IndexError`] is returned.
+    ```
+    use cart_lin::{HilbertIndices, IndexError};
+
+    assert_eq!(HilbertIndices::new([3, 0]).unwrap().count(), 0);
+
+    // Covered by the Hilbert curve of order 4 with 2^8 cells
+    assert!(HilbertIndices::<2, u16>::new([16, 16]).is_ok());
+    assert_eq!(HilbertIndices::<2, u8>::new([16, 2]).unwrap_err(), IndexError::Overflow);
+    ```
+     */
+    pub fn new(dim_size: [T; N]) -> Result<Self, IndexError<T>> {
+        let remaining = validate_shape(&dim_size)?;
+        return Self::from_extent([T::ZERO; N], dim_size, remaining);
+    }
+
+    /**
+    Creates a new [`HilbertIndices`] iterator over the box with the given lower and upper bounds. See
+    [`CartesianIndices::from_bounds`] for the requirements on the bounds.
+    ```
+    use cart_lin::HilbertIndices;
+
+    let cartiter = HilbertIndices::<2, i32>::from_bounds([[-1, 1], [-1, 1]]).unwrap();
+    assert_eq!(cartiter.collect::<Vec<_>>(), [[-1, -1], [-1, 0], [0, 0], [0, -1]]);
+    ```
+     */
+    pub fn from_bounds(bounds: [[T; 2]; N]) -> Result<Self, IndexError<T>> {
+        // Validates the bounds and the total number of elements
+        let remaining = CartesianIndices::from_bounds(bounds)?.remaining();
+
+        return Self::from_extent(
+            bounds.map(|limits| limits[0]),
+            bounds.map(|limits| limits[1] - limits[0]),
+            remaining,
+        );
+    }
+
+    /**
+    Creates a new [`HilbertIndices`] iterator from the (validated) lower bounds, extents and number of
+    elements of the box.
+     */
+    fn from_extent(start: [T; N], extent: [T; N], remaining: T) -> Result<Self, IndexError<T>> {
+        let order = extent
+            .iter()
+            .map(|size| axis_bits(*size))
+            .max()
+            .unwrap_or(0);
+        hilbert_side_and_len::<T, N>(order)?;
+        return Ok(Self {
+            distance: T::ZERO,
+            order,
+            start,
+            extent,
+            remaining,
+        });
+    }
+
+    /**
+    Returns the largest level `j` for which the sub-cube with side length `2^j` containing the given
+    cartesian index (relative to the lower bounds) lies outside of the box, or `None` if the cartesian
+    index lies inside of the box.
+     */
+    fn outside_level(&self, cart: &[T; N]) -> Option<u32> {
+        return (0..=self.order).rev().find(|level| {
+            cart.iter()
+                .zip(self.extent.iter())
+                .any(|(index, size)| (*index >> *level) << *level >= *size)
+        });
+    }
+}
+
+impl<const N: usize, T: IndexInt> Iterator for HilbertIndices<N, T> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == T::ZERO {
+            return None;
+        }
+        loop {
+            let mut cart = hilbert_to_cart_unchecked(self.distance, self.order);
+            match self.outside_level(&cart) {
+                // A sub-cube of side length 2^level contains 2^(N * level) consecutive distances. Since
+                // there are cartesian indices remaining, the next block start is smaller than the number
+                // of cells.
+                Some(level) => {
+                    let shift = level * N as u32;
+                    self.distance = ((self.distance >> shift) + T::ONE) << shift;
+                }
+                None => {
+                    self.distance += T::ONE;
+                    self.remaining = self.remaining - T::ONE;
+                    for (index, start) in cart.iter_mut().zip(self.start.iter()) {
+                        *index += *start;
+                    }
+                    return Some(cart);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining.try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+}
+
+impl<const N: usize, T: IndexInt> ExactSizeIterator for HilbertIndices<N, T> where
+    CartesianIndices<N, T>: ExactSizeIterator
+{
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for HilbertIndices<N, T> {}
@@ -0,0 +1,170 @@
+/*!
+The integer types which can be used for linear and cartesian indices.
+ */
+
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Rem, Shl, Shr, Sub,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/**
+A primitive integer type which can be used for linear and cartesian indices.
+
+All conversion functions of this crate as well as [`CartesianIndices`](crate::CartesianIndices) are generic
+over this trait, which is implemented for all primitive integer types. The default (and the type used in
+all other types of this crate, such as [`Shape`](crate::Shape)) is `usize`. Other types are useful if
+e.g. a GPU buffer is addressed with `u32` or if a dataset has more elements than `usize` can address on
+a 32 bit target. Overflow checks (see [`validate_shape`](crate::validate_shape)) are performed with
+respect to the chosen type:
+```
+use cart_lin::{IndexError, cart_to_lin, lin_to_cart};
+
+assert_eq!(cart_to_lin::<u32>(&[1, 2], &[2, 3]).unwrap(), 5);
+assert_eq!(lin_to_cart::<u128, 2>(5, &[2, 3]).unwrap(), [1, 2]);
+
+// 2^16 * 2^16 elements do not fit into an u32
+assert_eq!(cart_to_lin::<u32>(&[0, 0], &[1 << 16, 1 << 16]), Err(IndexError::Overflow));
+```
+For signed types, negative cartesian and linear indices are treated as out of bounds and negative
+dimension sizes are rejected by the checked functions:
+```
+use cart_lin::{IndexError, cart_to_lin, lin_to_cart};
+
+assert_eq!(
+    cart_to_lin::<i64>(&[-1, 2], &[2, 3]),
+    Err(IndexError::OutOfBounds { axis: 0, index: -1, bound: 2 })
+);
+assert_eq!(
+    lin_to_cart::<i64, 2>(0, &[2, -3]),
+    Err(IndexError::NegativeDimension { axis: 1, size: -3 })
+);
+```
+
+This trait is sealed and cannot be implemented outside of this crate.
+ */
+pub trait IndexInt:
+    private::Sealed
+    + Copy
+    + Debug
+    + Display
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + AddAssign
+    + MulAssign
+    + DivAssign
+    + TryFrom<usize>
+    + TryInto<usize>
+{
+    /// The value zero.
+    const ZERO: Self;
+
+    /// The value one.
+    const ONE: Self;
+
+    /// The largest value of this type.
+    const MAX: Self;
+
+    /// The size of this type in bits.
+    const BITS: u32;
+
+    /// Returns `true` if the value is smaller than zero. Always `false` for unsigned types.
+    fn is_negative(self) -> bool;
+
+    /// Returns the number of leading zeros in the binary representation.
+    fn leading_zeros(self) -> u32;
+
+    /// Checked addition, returns `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Checked subtraction, returns `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Checked multiplication, returns `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_index_int {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl IndexInt for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$t>::MAX;
+                const BITS: u32 = <$t>::BITS;
+
+                #[inline]
+                #[allow(unused_comparisons)]
+                fn is_negative(self) -> bool {
+                    return self < 0;
+                }
+
+                #[inline]
+                fn leading_zeros(self) -> u32 {
+                    return <$t>::leading_zeros(self);
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    return <$t>::checked_add(self, rhs);
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    return <$t>::checked_sub(self, rhs);
+                }
+
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    return <$t>::checked_mul(self, rhs);
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    return <$t>::wrapping_add(self, rhs);
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    return <$t>::wrapping_sub(self, rhs);
+                }
+
+                #[inline]
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    return <$t>::wrapping_mul(self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_index_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -1,38 +1,118 @@
 /*!
 [`cart_to_lin`]: crate::cart_to_lin
 [`cart_to_lin_unchecked`]: crate::cart_to_lin_unchecked
+[`cart_to_lin_boundary`]: crate::cart_to_lin_boundary
+[`Boundary`]: crate::Boundary
 [`lin_to_cart`]: crate::lin_to_cart
 [`lin_to_cart_unchecked`]: crate::lin_to_cart_unchecked
 [`lin_to_cart_dyn`]: crate::lin_to_cart_dyn
 [`lin_to_cart_dyn_unchecked`]: crate::lin_to_cart_dyn_unchecked
+[`cart_to_lin_unchecked_const`]: crate::cart_to_lin_unchecked_const
+[`lin_to_cart_unchecked_const`]: crate::lin_to_cart_unchecked_const
 [`CartesianIndices`]: crate::CartesianIndices
-[`Range`]: std::ops::Range
+[`CartesianIndices::new`]: crate::CartesianIndices::new
+[`CartesianIndices::try_new`]: crate::CartesianIndices::try_new
+[`CartesianIndices::enumerate_linear`]: crate::CartesianIndices::enumerate_linear
+[`CartesianIndicesDyn`]: crate::CartesianIndicesDyn
+[`CartesianIndicesBuilder`]: crate::CartesianIndicesBuilder
+[`EnumerateLinear`]: crate::EnumerateLinear
+[`SnakeIndices`]: crate::SnakeIndices
+[`TiledIndices`]: crate::TiledIndices
+[`TiledIndices::elements`]: crate::TiledIndices::elements
+[`cart_to_morton`]: crate::cart_to_morton
+[`morton_to_cart`]: crate::morton_to_cart
+[`MortonIndices`]: crate::MortonIndices
+[`cart_to_hilbert`]: crate::cart_to_hilbert
+[`hilbert_to_cart`]: crate::hilbert_to_cart
+[`HilbertIndices`]: crate::HilbertIndices
+[`Order`]: crate::Order
+[`RowMajor`]: crate::RowMajor
+[`ColumnMajor`]: crate::ColumnMajor
+[`PermutedLayout`]: crate::PermutedLayout
+[`StridedLayout`]: crate::StridedLayout
+[`PackedSymmetric`]: crate::PackedSymmetric
+[`PackedSymmetric::canonicalize`]: crate::PackedSymmetric::canonicalize
+[`PackedSymmetric::indices`]: crate::PackedSymmetric::indices
+[`PackedTriangular`]: crate::PackedTriangular
+[`PackedTriangular::cart_to_lin_symmetric`]: crate::PackedTriangular::cart_to_lin_symmetric
+[`PackedTriangular::indices`]: crate::PackedTriangular::indices
+[`Uplo`]: crate::Uplo
+[`BandedLayout`]: crate::BandedLayout
+[`BandedLayout::indices`]: crate::BandedLayout::indices
+[`Shape`]: crate::Shape
+[`ShapeDyn`]: crate::ShapeDyn
+[`FastShape`]: crate::FastShape
+[`IndexError`]: crate::IndexError
+[`IndexInt`]: crate::IndexInt
+[`validate_shape`]: crate::validate_shape
+[`Range`]: core::ops::Range
 
 A lightweight library for converting between linear and cartesian indices for any number of dimensions.
 
  */
 #![doc = include_str!("../docs/main.md")]
-
-/**
-Check whether the given indices are valid. This is the case if the length of `indices`
-is equal to the dimensionality of the data `N`, and if all individual axes indices are in bounds.
-*/
-fn valid_indices(indices: &[usize], dim_size: &[usize]) -> bool {
-    for (cart_index, bound) in indices.iter().zip(dim_size.iter()) {
-        if *cart_index >= *bound {
-            return false;
-        }
-    }
-    return indices.len() == dim_size.len();
-}
+#![allow(clippy::needless_return)]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod banded;
+mod boundary;
+mod cartesian_indices;
+mod error;
+mod fast_shape;
+mod hilbert;
+mod index_int;
+mod morton;
+mod order;
+mod packed;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod shape;
+mod snake;
+mod strided;
+mod tiled;
+mod traversal;
+
+pub use banded::{BandedIndices, BandedLayout};
+pub use boundary::{Boundary, cart_to_lin_boundary};
+#[cfg(feature = "alloc")]
+pub use cartesian_indices::CartesianIndicesDyn;
+pub use cartesian_indices::{CartesianIndices, CartesianIndicesBuilder, EnumerateLinear};
+pub use error::{IndexError, validate_shape};
+pub use fast_shape::FastShape;
+pub use hilbert::{
+    HilbertIndices, cart_to_hilbert, cart_to_hilbert_unchecked, hilbert_to_cart,
+    hilbert_to_cart_unchecked,
+};
+pub use index_int::IndexInt;
+pub use morton::{
+    MortonIndices, cart_to_morton, cart_to_morton_unchecked, morton_to_cart,
+    morton_to_cart_unchecked,
+};
+pub use order::{ColumnMajor, Order, PermutedLayout, RowMajor};
+pub use packed::{PackedSymmetric, PackedTriangular, SymmetricIndices, TriangularIndices, Uplo};
+#[cfg(feature = "rayon")]
+pub use parallel::ParCartesianIndices;
+pub use shape::Shape;
+#[cfg(feature = "alloc")]
+pub use shape::ShapeDyn;
+pub use snake::SnakeIndices;
+pub use strided::StridedLayout;
+pub use tiled::{TiledElements, TiledIndices};
 
 /**
 Convert a cartesian index into a linear index (row-major).
 
 This function takes two arguments -- cartesian indices and the size of each dimension as slices -- and uses them
 to calculate the corresponding linear index in row-major order. If the length of the cartesian index is not
-equal to the number of dimensions (= length of `dim_size`), or if any of the cartesian indices are out of bounds,
-this function returns `None`.
+equal to the number of dimensions (= length of `dim_size`), if any of the cartesian indices are out of bounds
+or if the shape overflows (see [`validate_shape`]), this function returns an [`IndexError`]. For other memory
+orders, see [`Order`].
 ```
 use cart_lin::cart_to_lin;
 
@@ -46,7 +126,7 @@ assert_eq!(cart_to_lin(&[1, 3], &dim_size).unwrap(), 8);
 assert_eq!(cart_to_lin(&[1, 4], &dim_size).unwrap(), 9);
 
 // Out-of-bounds cartesian indices:
-assert!(cart_to_lin(&[1, 5], &dim_size).is_none()); // matrix has five columns, hence the maximum column index is 4.
+assert!(cart_to_lin(&[1, 5], &dim_size).is_err()); // matrix has five columns, hence the maximum column index is 4.
 
 // 2 x 3 x 4 matrix
 let dim_size = [2, 3, 4];
@@ -63,19 +143,15 @@ assert_eq!(cart_to_lin(&[1, 0, 1], &dim_size).unwrap(), 13);
 assert_eq!(cart_to_lin(&[1, 0, 2], &dim_size).unwrap(), 14);
 ```
 */
-pub fn cart_to_lin(indices: &[usize], dim_size: &[usize]) -> Option<usize> {
-    if valid_indices(indices, dim_size) {
-        return Some(cart_to_lin_unchecked(indices, dim_size));
-    } else {
-        return None;
-    }
+pub fn cart_to_lin<T: IndexInt>(indices: &[T], dim_size: &[T]) -> Result<T, IndexError<T>> {
+    return RowMajor::cart_to_lin(indices, dim_size);
 }
 
 /**
 Like [`cart_to_lin`], but without the checks.
 
 Despite the name, this function itself is safe. However, the index received from this function might be invalid. Using
-such an invalid index may cause an out-of-bounds read.
+such an invalid index may cause an out-of-bounds read. If the computation overflows the index type, the result wraps around.
 ```
 use cart_lin::{cart_to_lin, cart_to_lin_unchecked};
 
@@ -87,18 +163,12 @@ assert_eq!(cart_to_lin(&[1, 4], &dim_size).unwrap(), 9);
 assert_eq!(cart_to_lin_unchecked(&[1, 4], &dim_size), 9);
 
 // Out-of-bounds cartesian indices:
-assert!(cart_to_lin(&[1, 5], &dim_size).is_none());
+assert!(cart_to_lin(&[1, 5], &dim_size).is_err());
 assert_eq!(cart_to_lin_unchecked(&[1, 5], &dim_size), 10); // Nonsensical value - matrix only has 10 entries (linear index 0 to 9)!
 ```
 */
-pub fn cart_to_lin_unchecked(indices: &[usize], dim_size: &[usize]) -> usize {
-    let mut index: usize = 0;
-    let mut multiplier: usize = 1;
-    for (cart_index, bound) in indices.iter().rev().zip(dim_size.iter().rev()) {
-        index += multiplier * cart_index;
-        multiplier *= *bound;
-    }
-    return index;
+pub fn cart_to_lin_unchecked<T: IndexInt>(indices: &[T], dim_size: &[T]) -> T {
+    return RowMajor::cart_to_lin_unchecked(indices, dim_size);
 }
 
 /**
@@ -106,22 +176,22 @@ Convert a linear index to a cartesian index (row-major).
 
 This function takes the linear index and the size of each dimension as a slice and uses them to
 calculate the corresponding cartesian index. If the linear index is out of bounds (= equal to
-or larger than the cartiter of all values in the `dim_size` vector), this function returns `None`.
+or larger than the product of all values in the `dim_size` vector) or if the shape overflows (see
+[`validate_shape`]), this function returns an [`IndexError`]. For other memory orders, see [`Order`].
 ```
 use cart_lin::lin_to_cart;
 
 let dim_size = [2, 3];
 assert_eq!([0, 2], lin_to_cart(2, &dim_size).unwrap());
 assert_eq!([1, 1], lin_to_cart(4, &dim_size).unwrap());
-assert!(lin_to_cart(6, &dim_size).is_none()); // Out of bounds
+assert!(lin_to_cart(6, &dim_size).is_err()); // Out of bounds
 ```
  */
-pub fn lin_to_cart<const N: usize>(index: usize, dim_size: &[usize; N]) -> Option<[usize; N]> {
-    if index >= dim_size.iter().fold(1, |acc, bound| acc * bound) {
-        return None;
-    } else {
-        return Some(lin_to_cart_unchecked(index, dim_size));
-    }
+pub fn lin_to_cart<T: IndexInt, const N: usize>(
+    index: T,
+    dim_size: &[T; N],
+) -> Result<[T; N], IndexError<T>> {
+    return RowMajor::lin_to_cart(index, dim_size);
 }
 
 /**
@@ -139,14 +209,12 @@ assert_eq!([0, 2], lin_to_cart(2, &dim_size).unwrap());
 assert_eq!([0, 2], lin_to_cart_unchecked(2, &dim_size));
 
 // Out-of-bounds linear indices:
-assert!(lin_to_cart(6, &dim_size).is_none()); // Out of bounds
+assert!(lin_to_cart(6, &dim_size).is_err()); // Out of bounds
 assert_eq!([0, 0], lin_to_cart_unchecked(6, &dim_size)); // Nonsensical value (wrapping around)
 ```
  */
-pub fn lin_to_cart_unchecked<const N: usize>(index: usize, dim_size: &[usize; N]) -> [usize; N] {
-    let mut indices = [0; N];
-    lin_to_cart_dyn_unchecked(index, dim_size, indices.as_mut_slice());
-    return indices;
+pub fn lin_to_cart_unchecked<T: IndexInt, const N: usize>(index: T, dim_size: &[T; N]) -> [T; N] {
+    return RowMajor::lin_to_cart_unchecked(index, dim_size);
 }
 
 /**
@@ -154,10 +222,10 @@ Like [`lin_to_cart`], but mutates `cart_indices` in place instead of returning a
 
 While [`lin_to_cart`] expects arrays (size known at compile time), this function works with slices
 (whose length is not known until runtime and may dynamically change).
-If the length of `bounds` and `cart_indices` is not identical or the linear index is out of bounds, this function
-returns an error (and does not change `cart_indices`).
+If the length of `dim_size` and `cart_indices` is not identical, the linear index is out of bounds or the shape
+overflows, this function returns an [`IndexError`] (and does not change `cart_indices`).
 ```
-use cart_lin::lin_to_cart_dyn;
+use cart_lin::{IndexError, lin_to_cart_dyn};
 
 let dim_size = vec![2, 3];
 let mut indices = vec![0, 0];
@@ -170,22 +238,18 @@ assert_eq!(&[1, 1], indices.as_slice());
 
 // Incorrect usage: indices vector is too short -> Error
 let mut indices = vec![0];
-assert!(lin_to_cart_dyn(4, dim_size.as_slice(), indices.as_mut_slice()).is_err());
+assert_eq!(
+    lin_to_cart_dyn(4, dim_size.as_slice(), indices.as_mut_slice()),
+    Err(IndexError::DimensionMismatch { expected: 2, got: 1 })
+);
 ```
  */
-pub fn lin_to_cart_dyn(
-    index: usize,
-    dim_size: &[usize],
-    cart_indices: &mut [usize],
-) -> Result<(), &'static str> {
-    if dim_size.len() != cart_indices.len()
-        || index >= dim_size.iter().fold(1, |acc, bound| acc * bound)
-    {
-        return Err("length of slices not equal or index out of bounds");
-    } else {
-        lin_to_cart_dyn_unchecked(index, dim_size, cart_indices);
-        return Ok(());
-    }
+pub fn lin_to_cart_dyn<T: IndexInt>(
+    index: T,
+    dim_size: &[T],
+    cart_indices: &mut [T],
+) -> Result<(), IndexError<T>> {
+    return RowMajor::lin_to_cart_dyn(index, dim_size, cart_indices);
 }
 
 /**
@@ -206,142 +270,74 @@ lin_to_cart_dyn_unchecked(4, dim_size.as_slice(), indices.as_mut_slice());
 assert_eq!(&[1], indices.as_slice());
 ```
  */
-pub fn lin_to_cart_dyn_unchecked(index: usize, dim_size: &[usize], cart_indices: &mut [usize]) {
-    // Make the index mutable
-    let mut index = index;
-
-    // Fill up the indices from back to front by performing modulo and truncating
-    // integer divisons
-    for (idx, bound) in cart_indices.iter_mut().rev().zip(dim_size.iter().rev()) {
-        let remainder = index % *bound;
-        index = index / *bound;
-        *idx = remainder;
-    }
+pub fn lin_to_cart_dyn_unchecked<T: IndexInt>(index: T, dim_size: &[T], cart_indices: &mut [T]) {
+    RowMajor::lin_to_cart_dyn_unchecked(index, dim_size, cart_indices);
 }
 
 /**
-An iterator over all cartesian indices within the input dimension sizes.
- */
-#[derive(Debug)]
-pub struct CartesianIndices<const N: usize> {
-    current: usize,
-    max: usize,
-    limit_deltas: [usize; N],
-    bounds: [[usize; 2]; N],
-}
+Like [`cart_to_lin_unchecked`], but usable in constant expressions.
 
-impl<const N: usize> CartesianIndices<N> {
-    /**
-    Creates a new `CartesianIndices` iterator using the given dimension sizes.
-    ```
-    use cart_lin::CartesianIndices;
-
-    let mut cartiter = CartesianIndices::new([3]);
-    assert_eq!(cartiter.next(), Some([0]));
-    assert_eq!(cartiter.next(), Some([1]));
-    assert_eq!(cartiter.next(), Some([2]));
-    assert_eq!(cartiter.next(), None);
-
-    let mut cartiter = CartesianIndices::new([1, 3]);
-    assert_eq!(cartiter.next(), Some([0, 0]));
-    assert_eq!(cartiter.next(), Some([0, 1]));
-    assert_eq!(cartiter.next(), Some([0, 2]));
-    assert_eq!(cartiter.next(), None);
-    ```
-     */
-    pub fn new(dim_size: [usize; N]) -> Self {
-        let mut bounds = [[0, 0]; N];
-        for (limits, dim) in bounds.iter_mut().zip(dim_size.into_iter()) {
-            limits[1] = dim;
-        }
-
-        return Self::from_bounds_unchecked(bounds);
-    }
+Since trait methods cannot be called in a `const fn` on stable Rust, this function is restricted to `usize`
+indices instead of being generic over [`IndexInt`]. It returns the same values as [`cart_to_lin_unchecked`]
+(row-major order), including the wrapped values on overflow.
+```
+use cart_lin::cart_to_lin_unchecked_const;
 
-    /**
-    Creates a new [`CartesianIndices`] using lower and upper bounds of each dimension.
-
-    The lower and upper bounds must be given as an two-element array and the lower
-    bound must be smaller than or equal to the upper bound:
-    ```
-    use cart_lin::CartesianIndices;
-
-    // Valid input:
-    // Indices for first dimension are between 1 and 3 (excluded)
-    // Indices for second dimension are between 2 and 3 (excluded)
-    let mut cartiter = CartesianIndices::from_bounds([[1, 3], [2, 5]]).expect("bounds must be strictly monotonic increasing");
-    assert_eq!(cartiter.next(), Some([1, 2]));
-    assert_eq!(cartiter.next(), Some([1, 3]));
-    assert_eq!(cartiter.next(), Some([1, 4]));
-    assert_eq!(cartiter.next(), Some([2, 2]));
-    assert_eq!(cartiter.next(), Some([2, 3]));
-    assert_eq!(cartiter.next(), Some([2, 4]));
-    assert_eq!(cartiter.next(), None);
-
-    // Invalid input:
-    // Lower bound for first dimension is 1, but upper bound is 0?
-    assert!(CartesianIndices::from_bounds([[1, 0], [2, 3]]).is_none());
-
-    // Invalid input:
-    // Lower bound for first dimension is 1, but upper bound is also 1?
-    assert!(CartesianIndices::from_bounds([[1, 1], [2, 3]]).is_none());
-    ```
-     */
-    pub fn from_bounds(bounds: [[usize; 2]; N]) -> Option<Self> {
-        for index_limits in bounds.iter() {
-            if index_limits[1] <= index_limits[0] {
-                return None;
-            }
-        }
-
-        return Some(Self::from_bounds_unchecked(bounds));
-    }
+const DIM_SIZE: [usize; 3] = [2, 3, 4];
+const LAST: usize = cart_to_lin_unchecked_const(&[1, 2, 3], &DIM_SIZE);
+assert_eq!(LAST, 23);
+```
+ */
+pub const fn cart_to_lin_unchecked_const(indices: &[usize], dim_size: &[usize]) -> usize {
+    let mut index: usize = 0;
+    let mut multiplier: usize = 1;
 
-    /**
-    Like [`CartesianIndices::from_bounds`], but without the checks.
-
-    Despite the name, this function itself is safe. However, the index received from this function might be invalid. Using
-    such an invalid index may cause an out-of-bounds read.
-    */
-    pub fn from_bounds_unchecked(bounds: [[usize; 2]; N]) -> Self {
-        let mut max = 1;
-        let mut limit_deltas = [0; N];
-        for (limits, delta) in bounds.iter().zip(limit_deltas.iter_mut()) {
-            *delta = limits[1] - limits[0];
-            max = max * *delta;
-        }
-
-        return Self {
-            current: 0,
-            max,
-            limit_deltas,
-            bounds,
-        };
+    // Iterate from the fastest to the slowest varying axis, see `order::encode`
+    let mut k = 0;
+    while k < indices.len() && k < dim_size.len() {
+        index = index.wrapping_add(multiplier.wrapping_mul(indices[indices.len() - 1 - k]));
+        multiplier = multiplier.wrapping_mul(dim_size[dim_size.len() - 1 - k]);
+        k += 1;
     }
+    return index;
 }
 
-impl<const N: usize> Iterator for CartesianIndices<N> {
-    type Item = [usize; N];
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.max {
-            return None;
-        }
-
-        // Calculate the linear indices
-        let mut res = lin_to_cart_unchecked(self.current, &self.limit_deltas);
-
-        // Add offsets from lower limits
-        for (r, limits) in res.iter_mut().zip(self.bounds.iter()) {
-            *r += limits[0];
-        }
+/**
+Like [`lin_to_cart_unchecked`], but usable in constant expressions, e.g. to compute lookup tables
+for fixed shapes at compile time.
 
-        self.current += 1;
-        return Some(res);
+Since trait methods cannot be called in a `const fn` on stable Rust, this function is restricted to `usize`
+indices instead of being generic over [`IndexInt`]. It returns the same values as [`lin_to_cart_unchecked`]
+(row-major order).
+```
+use cart_lin::lin_to_cart_unchecked_const;
+
+const DIM_SIZE: [usize; 2] = [2, 3];
+const TABLE: [[usize; 2]; 6] = {
+    let mut table = [[0; 2]; 6];
+    let mut index = 0;
+    while index < table.len() {
+        table[index] = lin_to_cart_unchecked_const(index, &DIM_SIZE);
+        index += 1;
     }
+    table
+};
+assert_eq!(TABLE, [[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]);
+```
+ */
+pub const fn lin_to_cart_unchecked_const<const N: usize>(
+    index: usize,
+    dim_size: &[usize; N],
+) -> [usize; N] {
+    let mut indices = [0; N];
+    let mut index = index;
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.current = n;
-        return self.next();
+    // Iterate from the fastest to the slowest varying axis, see `order::decode`
+    let mut axis = N;
+    while axis > 0 {
+        axis -= 1;
+        indices[axis] = index % dim_size[axis];
+        index /= dim_size[axis];
     }
+    return indices;
 }
@@ -0,0 +1,304 @@
+/*!
+Conversion between cartesian indices and Morton codes (Z-order curve).
+ */
+
+use core::iter::FusedIterator;
+
+use crate::cartesian_indices::CartesianIndices;
+use crate::error::{IndexError, check_indices, check_linear, validate_shape};
+use crate::index_int::IndexInt;
+
+/**
+Returns the number of bits required for the indices of an axis with the given size.
+ */
+pub(crate) fn axis_bits<T: IndexInt>(size: T) -> u32 {
+    if size <= T::ONE {
+        return 0;
+    }
+    return T::BITS - (size - T::ONE).leading_zeros();
+}
+
+/**
+Returns the number of Morton codes for the given shape (the product of all dimension sizes, each rounded up
+to the next power of two). Returns an error if the shape is invalid or if the codes do not fit into `T`.
+ */
+fn morton_len<T: IndexInt>(dim_size: &[T]) -> Result<T, IndexError<T>> {
+    validate_shape(dim_size)?;
+    let mut len = T::ONE;
+    let two = T::ONE + T::ONE;
+    for size in dim_size.iter() {
+        for _ in 0..axis_bits(*size) {
+            len = len.checked_mul(two).ok_or(IndexError::Overflow)?;
+        }
+    }
+    return Ok(len);
+}
+
+/**
+Convert a cartesian index into a Morton code (Z-order curve).
+
+The Morton code is calculated by interleaving the bits of the individual indices, starting with the least
+significant bit of the last axis (like in row-major order, the last index changes fastest). Each axis only
+contributes as many bits as needed for its size (`ceil(log2(size))`), hence dimension sizes do not need to
+be powers of two and may differ from each other. For shapes whose sizes are not powers of two, some
+codes are not used: all codes lie in `0..len`, where `len` is the product of all dimension sizes rounded
+up to the next power of two.
+
+Neighboring cartesian indices are likely to have similar Morton codes, which improves the memory locality
+e.g. of spatial indices or textures. Like [`cart_to_lin`](crate::cart_to_lin), this function returns an
+[`IndexError`] if the length of the cartesian index is not equal to the number of dimensions or if any of
+the cartesian indices are out of bounds. If `len` does not fit into the index type, [`IndexError::Overflow`]
+is returned.
+```
+use cart_lin::cart_to_morton;
+
+// 4 x 4 matrix:
+// [ 0  1  4  5]
+// [ 2  3  6  7]
+// [ 8  9 12 13]
+// [10 11 14 15]
+let dim_size = [4, 4];
+assert_eq!(cart_to_morton(&[0, 1], &dim_size).unwrap(), 1);
+assert_eq!(cart_to_morton(&[1, 0], &dim_size).unwrap(), 2);
+assert_eq!(cart_to_morton(&[1, 2], &dim_size).unwrap(), 6);
+assert_eq!(cart_to_morton(&[3, 3], &dim_size).unwrap(), 15);
+
+// 2 x 8 matrix: The second axis contributes two more bits than the first one
+// [0 1 4 5  8  9 12 13]
+// [2 3 6 7 10 11 14 15]
+assert_eq!(cart_to_morton(&[1, 2], &[2, 8]).unwrap(), 6);
+assert_eq!(cart_to_morton(&[0, 7], &[2, 8]).unwrap(), 13);
+
+// Out-of-bounds cartesian indices:
+assert!(cart_to_morton(&[2, 0], &[2, 8]).is_err());
+```
+ */
+pub fn cart_to_morton<T: IndexInt>(indices: &[T], dim_size: &[T]) -> Result<T, IndexError<T>> {
+    check_indices(indices, dim_size)?;
+    morton_len(dim_size)?;
+    return Ok(cart_to_morton_unchecked(indices, dim_size));
+}
+
+/**
+Like [`cart_to_morton`], but without the checks.
+
+Despite the name, this function itself is safe. However, the code received from this function might be
+invalid. Using such an invalid code may cause an out-of-bounds read. Bits which do not fit into the index
+type are discarded.
+```
+use cart_lin::cart_to_morton_unchecked;
+
+assert_eq!(cart_to_morton_unchecked(&[1, 2], &[4, 4]), 6);
+```
+ */
+pub fn cart_to_morton_unchecked<T: IndexInt>(indices: &[T], dim_size: &[T]) -> T {
+    let max_bits = dim_size.iter().map(|size| axis_bits(*size)).max();
+    let mut code = T::ZERO;
+    let mut position = 0;
+    for level in 0..max_bits.unwrap_or(0) {
+        for (index, size) in indices.iter().zip(dim_size.iter()).rev() {
+            if axis_bits(*size) > level && position < T::BITS {
+                code = code | (((*index >> level) & T::ONE) << position);
+                position += 1;
+            }
+        }
+    }
+    return code;
+}
+
+/**
+Convert a Morton code into a cartesian index, the inverse of [`cart_to_morton`].
+
+If the code is equal to or larger than the number of codes (see [`cart_to_morton`]), an
+[`IndexError::LinearOutOfRange`] is returned. If the code is not used by the shape (which is possible if
+a dimension size is not a power of two), the decoded cartesian index is out of bounds and an
+[`IndexError::OutOfBounds`] is returned.
+```
+use cart_lin::{IndexError, morton_to_cart};
+
+let dim_size = [3, 4];
+assert_eq!(morton_to_cart(6, &dim_size).unwrap(), [1, 2]);
+assert_eq!(morton_to_cart(9, &dim_size).unwrap(), [2, 1]);
+
+// Code 10 would belong to the cartesian index [3, 0], which lies outside of the 3 x 4 matrix
+assert_eq!(
+    morton_to_cart(10, &dim_size),
+    Err(IndexError::OutOfBounds { axis: 0, index: 3, bound: 3 })
+);
+assert_eq!(
+    morton_to_cart(16, &dim_size),
+    Err(IndexError::LinearOutOfRange { index: 16, len: 16 })
+);
+```
+ */
+pub fn morton_to_cart<T: IndexInt, const N: usize>(
+    code: T,
+    dim_size: &[T; N],
+) -> Result<[T; N], IndexError<T>> {
+    check_linear(code, morton_len(dim_size)?)?;
+    let cart = morton_to_cart_unchecked(code, dim_size);
+    check_indices(&cart, dim_size)?;
+    return Ok(cart);
+}
+
+/**
+Like [`morton_to_cart`], but without the checks.
+
+Despite the name, this function itself is safe. However, the index received from this function might be
+invalid. Using such an invalid index may cause an out-of-bounds read.
+```
+use cart_lin::morton_to_cart_unchecked;
+
+assert_eq!(morton_to_cart_unchecked(6, &[4, 4]), [1, 2]);
+
+// Unused code of a 3 x 4 matrix
+assert_eq!(morton_to_cart_unchecked(10, &[3, 4]), [3, 0]);
+```
+ */
+pub fn morton_to_cart_unchecked<T: IndexInt, const N: usize>(code: T, dim_size: &[T; N]) -> [T; N] {
+    let bits = dim_size.map(axis_bits);
+    let mut cart = [T::ZERO; N];
+    let mut position = 0;
+    for level in 0..bits.iter().copied().max().unwrap_or(0) {
+        for (index, axis_bits) in cart.iter_mut().zip(bits.iter()).rev() {
+            if *axis_bits > level && position < T::BITS {
+                *index = *index | (((code >> position) & T::ONE) << level);
+                position += 1;
+            }
+        }
+    }
+    return cart;
+}
+
+/**
+An iterator over all cartesian indices of a shape in Morton order (Z-order curve), i.e. in ascending
+order of their Morton codes (see [`cart_to_morton`]).
+
+If a dimension size is not a power of two, the unused codes are skipped block-wise: as soon as the
+decoded cartesian index is out of bounds, all codes sharing its out-of-bounds prefix are skipped at once.
+```
+use cart_lin::{MortonIndices, cart_to_morton};
+
+let dim_size = [3, 3];
+let mut cartiter = MortonIndices::new(dim_size).unwrap();
+assert_eq!(cartiter.len(), 9);
+assert_eq!(cartiter.next(), Some([0, 0]));
+assert_eq!(cartiter.next(), Some([0, 1]));
+assert_eq!(cartiter.next(), Some([1, 0]));
+assert_eq!(cartiter.next(), Some([1, 1]));
+assert_eq!(cartiter.next(), Some([0, 2]));
+assert_eq!(cartiter.next(), Some([1, 2]));
+assert_eq!(cartiter.next(), Some([2, 0]));
+
+// The Morton codes are strictly increasing
+let mut last = None;
+for cart in MortonIndices::new([5, 6, 7]).unwrap() {
+    let code = cart_to_morton(&cart, &[5, 6, 7]).unwrap();
+    assert!(last < Some(code));
+    last = Some(code);
+}
+```
+ */
+#[derive(Debug, Clone)]
+pub struct MortonIndices<const N: usize, T: IndexInt = usize> {
+    // Next Morton code to be examined
+    code: T,
+    // Number of remaining cartesian indices
+    remaining: T,
+    dim_size: [T; N],
+    bits: [u32; N],
+}
+
+impl<const N: usize, T: IndexInt> MortonIndices<N, T> {
+    /**
+    Creates a new [`MortonIndices`] iterator using the given dimension sizes.
+
+    If the shape is invalid (see [`validate_shape`]) or if its Morton codes do not fit into the
+    index type (see [`cart_to_morton`]), an [`IndexError`] is returned.
+    ```
+    use cart_lin::{IndexError, MortonIndices};
+
+    assert_eq!(MortonIndices::new([2, 0]).unwrap().count(), 0);
+
+    // 129 x 129 elements fit into an u16, but their Morton codes require 8 + 8 bits
+    assert!(MortonIndices::<2, u16>::new([128, 128]).is_ok());
+    assert_eq!(MortonIndices::<2, u16>::new([129, 129]).unwrap_err(), IndexError::Overflow);
+    ```
+     */
+    pub fn new(dim_size: [T; N]) -> Result<Self, IndexError<T>> {
+        morton_len(&dim_size)?;
+        return Ok(Self {
+            code: T::ZERO,
+            remaining: validate_shape(&dim_size)?,
+            dim_size,
+            bits: dim_size.map(axis_bits),
+        });
+    }
+
+    /**
+    If the cartesian index is out of bounds, returns the position of the highest code bit from which on
+    all codes with the same prefix are out of bounds as well.
+     */
+    fn out_of_bounds_position(&self, cart: &[T; N]) -> Option<u32> {
+        let mut res = None;
+        for (axis, (index, size)) in cart.iter().zip(self.dim_size.iter()).enumerate() {
+            if *index < *size {
+                continue;
+            }
+
+            // Highest bit level in which the index differs from the largest valid index (where the index
+            // has a one and the largest valid index has a zero)
+            let level = T::BITS - 1 - (*index ^ (*size - T::ONE)).leading_zeros();
+
+            // Position of the bit of this axis and level within the code
+            let mut position = 0;
+            for lower_level in 0..level {
+                position += self.bits.iter().filter(|bits| **bits > lower_level).count() as u32;
+            }
+            position += self.bits[axis + 1..]
+                .iter()
+                .filter(|bits| **bits > level)
+                .count() as u32;
+
+            res = res.max(Some(position));
+        }
+        return res;
+    }
+}
+
+impl<const N: usize, T: IndexInt> Iterator for MortonIndices<N, T> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == T::ZERO {
+            return None;
+        }
+        loop {
+            let cart = morton_to_cart_unchecked(self.code, &self.dim_size);
+            match self.out_of_bounds_position(&cart) {
+                // Skip the block of codes sharing the out-of-bounds prefix. Since there are cartesian
+                // indices remaining, the next block start is smaller than the number of codes.
+                Some(position) => self.code = ((self.code >> position) + T::ONE) << position,
+                None => {
+                    self.code += T::ONE;
+                    self.remaining = self.remaining - T::ONE;
+                    return Some(cart);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining.try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+}
+
+impl<const N: usize, T: IndexInt> ExactSizeIterator for MortonIndices<N, T> where
+    CartesianIndices<N, T>: ExactSizeIterator
+{
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for MortonIndices<N, T> {}
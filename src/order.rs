@@ -0,0 +1,447 @@
+/*!
+Memory orders (row-major, column-major and arbitrary axis permutations) for the conversion between linear and cartesian indices.
+ */
+
+use crate::error::{IndexError, check_indices, check_linear, validate_shape};
+use crate::index_int::IndexInt;
+
+/**
+Mixed-radix encoding of a cartesian index. `axes` yields pairs of axis index and axis size,
+starting with the fastest varying axis.
+ */
+pub(crate) fn encode<'a, T: IndexInt + 'a>(axes: impl Iterator<Item = (&'a T, &'a T)>) -> T {
+    let mut index = T::ZERO;
+    let mut multiplier = T::ONE;
+    // Overflows only occur for out-of-bounds indices or overflowing shapes, which the unchecked
+    // conversions do not detect. Their results consistently wrap around in that case.
+    for (cart_index, bound) in axes {
+        index = index.wrapping_add(multiplier.wrapping_mul(*cart_index));
+        multiplier = multiplier.wrapping_mul(*bound);
+    }
+    return index;
+}
+
+/**
+Mixed-radix decoding of a linear index, the inverse of [`encode`]. `axes` yields pairs of output
+axis index and axis size, starting with the fastest varying axis.
+ */
+pub(crate) fn decode<'a, T: IndexInt + 'a>(
+    index: T,
+    axes: impl Iterator<Item = (&'a mut T, &'a T)>,
+) {
+    // Make the index mutable
+    let mut index = index;
+
+    // Fill up the indices from the fastest to the slowest varying axis by performing
+    // modulo and truncating integer divisons
+    for (idx, bound) in axes {
+        let remainder = index % *bound;
+        index /= *bound;
+        *idx = remainder;
+    }
+}
+
+/**
+A memory order which defines how cartesian indices are mapped onto linear indices.
+
+This trait is implemented by the marker types [`RowMajor`] (last index changes fastest, e.g. used by
+[ndarray](https://crates.io/crates/ndarray) and C arrays) and [`ColumnMajor`] (first index changes
+fastest, e.g. used by [nalgebra](https://crates.io/crates/nalgebra) and Fortran). The free functions
+of this crate such as [`cart_to_lin`](crate::cart_to_lin) use [`RowMajor`]. Writing code which is
+generic over `O: Order` allows using the same code path for buffers with either memory order:
+```
+use cart_lin::{ColumnMajor, Order, RowMajor};
+
+fn last_element<O: Order>(data: &[f64], dim_size: &[usize]) -> f64 {
+    let last: Vec<usize> = dim_size.iter().map(|d| d - 1).collect();
+    return data[O::cart_to_lin(&last, dim_size).unwrap()];
+}
+
+// 2 x 3 matrix
+// [1 2 3]
+// [4 5 6]
+let row_major = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+let column_major = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+assert_eq!(last_element::<RowMajor>(&row_major, &[2, 3]), 6.0);
+assert_eq!(last_element::<ColumnMajor>(&column_major, &[2, 3]), 6.0);
+```
+
+Only [`Order::cart_to_lin_unchecked`], [`Order::lin_to_cart_dyn_unchecked`] and [`Order::strides`]
+need to be implemented, all other methods are derived from them.
+ */
+pub trait Order {
+    /**
+    Like [`Order::cart_to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read. See also [`cart_to_lin_unchecked`](crate::cart_to_lin_unchecked).
+     */
+    fn cart_to_lin_unchecked<T: IndexInt>(indices: &[T], dim_size: &[T]) -> T;
+
+    /**
+    Like [`Order::lin_to_cart_dyn`], but without the checks.
+    See also [`lin_to_cart_dyn_unchecked`](crate::lin_to_cart_dyn_unchecked).
+     */
+    fn lin_to_cart_dyn_unchecked<T: IndexInt>(index: T, dim_size: &[T], cart_indices: &mut [T]);
+
+    /**
+    Writes the stride of each axis (the distance between the linear indices of two neighboring
+    elements along the axis) into `strides`.
+    ```
+    use cart_lin::{ColumnMajor, Order, RowMajor};
+
+    let mut strides = [0; 3];
+    RowMajor::strides(&[2, 3, 4], &mut strides);
+    assert_eq!(strides, [12, 4, 1]);
+    ColumnMajor::strides(&[2, 3, 4], &mut strides);
+    assert_eq!(strides, [1, 2, 6]);
+    ```
+     */
+    fn strides<T: IndexInt>(dim_size: &[T], strides: &mut [T]);
+
+    /**
+    Convert a cartesian index into a linear index using this memory order.
+
+    If the length of the cartesian index is not equal to the number of dimensions (= length of `dim_size`),
+    if any of the cartesian indices are out of bounds or if the shape overflows (see
+    [`validate_shape`](crate::validate_shape)), this function returns an error. See also [`cart_to_lin`](crate::cart_to_lin).
+    ```
+    use cart_lin::{ColumnMajor, Order, RowMajor};
+
+    // 2 x 3 matrix
+    let dim_size = [2, 3];
+    assert_eq!(RowMajor::cart_to_lin(&[1, 0], &dim_size).unwrap(), 3);
+    assert_eq!(ColumnMajor::cart_to_lin(&[1, 0], &dim_size).unwrap(), 1);
+    assert_eq!(ColumnMajor::cart_to_lin(&[0, 1], &dim_size).unwrap(), 2);
+    assert!(ColumnMajor::cart_to_lin(&[2, 0], &dim_size).is_err()); // Out of bounds
+    ```
+     */
+    fn cart_to_lin<T: IndexInt>(indices: &[T], dim_size: &[T]) -> Result<T, IndexError<T>> {
+        check_indices(indices, dim_size)?;
+        validate_shape(dim_size)?;
+        return Ok(Self::cart_to_lin_unchecked(indices, dim_size));
+    }
+
+    /**
+    Convert a linear index to a cartesian index using this memory order.
+
+    If the linear index is out of bounds (= equal to or larger than the product of all values in
+    `dim_size`) or if the shape overflows (see [`validate_shape`](crate::validate_shape)), this function
+    returns an error. See also [`lin_to_cart`](crate::lin_to_cart).
+    ```
+    use cart_lin::{ColumnMajor, Order, RowMajor};
+
+    // 2 x 3 matrix
+    let dim_size = [2, 3];
+    assert_eq!(RowMajor::lin_to_cart(1, &dim_size).unwrap(), [0, 1]);
+    assert_eq!(ColumnMajor::lin_to_cart(1, &dim_size).unwrap(), [1, 0]);
+    assert_eq!(ColumnMajor::lin_to_cart(4, &dim_size).unwrap(), [0, 2]);
+    assert!(ColumnMajor::lin_to_cart(6, &dim_size).is_err()); // Out of bounds
+    ```
+     */
+    fn lin_to_cart<T: IndexInt, const N: usize>(
+        index: T,
+        dim_size: &[T; N],
+    ) -> Result<[T; N], IndexError<T>> {
+        check_linear(index, validate_shape(dim_size)?)?;
+        return Ok(Self::lin_to_cart_unchecked(index, dim_size));
+    }
+
+    /**
+    Like [`Order::lin_to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read. See also [`lin_to_cart_unchecked`](crate::lin_to_cart_unchecked).
+     */
+    fn lin_to_cart_unchecked<T: IndexInt, const N: usize>(index: T, dim_size: &[T; N]) -> [T; N] {
+        let mut indices = [T::ZERO; N];
+        Self::lin_to_cart_dyn_unchecked(index, dim_size, indices.as_mut_slice());
+        return indices;
+    }
+
+    /**
+    Like [`Order::lin_to_cart`], but mutates `cart_indices` in place instead of returning a new array.
+
+    If the length of `dim_size` and `cart_indices` is not identical, the linear index is out of bounds or the shape
+    overflows, this function returns an error (and does not change `cart_indices`). See also [`lin_to_cart_dyn`](crate::lin_to_cart_dyn).
+    ```
+    use cart_lin::{ColumnMajor, Order};
+
+    let dim_size = vec![2, 3];
+    let mut indices = vec![0, 0];
+
+    assert!(ColumnMajor::lin_to_cart_dyn(3, dim_size.as_slice(), indices.as_mut_slice()).is_ok());
+    assert_eq!(&[1, 1], indices.as_slice());
+    ```
+     */
+    fn lin_to_cart_dyn<T: IndexInt>(
+        index: T,
+        dim_size: &[T],
+        cart_indices: &mut [T],
+    ) -> Result<(), IndexError<T>> {
+        if dim_size.len() != cart_indices.len() {
+            return Err(IndexError::DimensionMismatch {
+                expected: dim_size.len(),
+                got: cart_indices.len(),
+            });
+        }
+        check_linear(index, validate_shape(dim_size)?)?;
+        Self::lin_to_cart_dyn_unchecked(index, dim_size, cart_indices);
+        return Ok(());
+    }
+}
+
+/**
+Row-major order (also called C order): The last index changes fastest.
+
+This is the order used by all free functions of this crate.
+```
+use cart_lin::{Order, RowMajor};
+
+// 2 x 3 matrix with the following linear indexing order:
+// [0 1 2]
+// [3 4 5]
+assert_eq!(RowMajor::cart_to_lin(&[0, 2], &[2, 3]).unwrap(), 2);
+assert_eq!(RowMajor::lin_to_cart(3, &[2, 3]).unwrap(), [1, 0]);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RowMajor;
+
+impl Order for RowMajor {
+    fn cart_to_lin_unchecked<T: IndexInt>(indices: &[T], dim_size: &[T]) -> T {
+        return encode(indices.iter().rev().zip(dim_size.iter().rev()));
+    }
+
+    fn lin_to_cart_dyn_unchecked<T: IndexInt>(index: T, dim_size: &[T], cart_indices: &mut [T]) {
+        decode(
+            index,
+            cart_indices.iter_mut().rev().zip(dim_size.iter().rev()),
+        );
+    }
+
+    fn strides<T: IndexInt>(dim_size: &[T], strides: &mut [T]) {
+        let mut multiplier = T::ONE;
+        for (stride, bound) in strides.iter_mut().rev().zip(dim_size.iter().rev()) {
+            *stride = multiplier;
+            // The product of all dimension sizes is never used as a stride, hence the last
+            // multiplication is allowed to wrap
+            multiplier = multiplier.wrapping_mul(*bound);
+        }
+    }
+}
+
+/**
+Column-major order (also called Fortran order): The first index changes fastest.
+```
+use cart_lin::{ColumnMajor, Order};
+
+// 2 x 3 matrix with the following linear indexing order:
+// [0 2 4]
+// [1 3 5]
+assert_eq!(ColumnMajor::cart_to_lin(&[0, 2], &[2, 3]).unwrap(), 4);
+assert_eq!(ColumnMajor::lin_to_cart(3, &[2, 3]).unwrap(), [1, 1]);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColumnMajor;
+
+impl Order for ColumnMajor {
+    fn cart_to_lin_unchecked<T: IndexInt>(indices: &[T], dim_size: &[T]) -> T {
+        return encode(indices.iter().zip(dim_size.iter()));
+    }
+
+    fn lin_to_cart_dyn_unchecked<T: IndexInt>(index: T, dim_size: &[T], cart_indices: &mut [T]) {
+        decode(index, cart_indices.iter_mut().zip(dim_size.iter()));
+    }
+
+    fn strides<T: IndexInt>(dim_size: &[T], strides: &mut [T]) {
+        let mut multiplier = T::ONE;
+        for (stride, bound) in strides.iter_mut().zip(dim_size.iter()) {
+            *stride = multiplier;
+            multiplier = multiplier.wrapping_mul(*bound);
+        }
+    }
+}
+
+/**
+A memory layout where the order of the axes in memory is given by a permutation.
+
+The permutation lists the axes of the cartesian index from the slowest to the fastest varying
+axis in memory. For example, the permutation `[0, 1, 2]` describes row-major order, `[2, 1, 0]`
+describes column-major order and `[0, 2, 3, 1]` describes an NHWC tensor which is indexed in
+NCHW order. The conversion uses the same mixed-radix arithmetic as [`RowMajor`] and [`ColumnMajor`].
+```
+use cart_lin::PermutedLayout;
+
+// Tensor with the logical axes N, C, H, W which is stored as NHWC
+// (the channel axis changes fastest in memory)
+let nhwc = PermutedLayout::new([0, 2, 3, 1]).expect("valid permutation");
+
+// Logical dimension sizes in NCHW order
+let dim_size = [1, 3, 2, 2];
+assert_eq!(nhwc.cart_to_lin(&[0, 0, 0, 0], &dim_size).unwrap(), 0);
+assert_eq!(nhwc.cart_to_lin(&[0, 1, 0, 0], &dim_size).unwrap(), 1);
+assert_eq!(nhwc.cart_to_lin(&[0, 2, 0, 0], &dim_size).unwrap(), 2);
+assert_eq!(nhwc.cart_to_lin(&[0, 0, 0, 1], &dim_size).unwrap(), 3);
+assert_eq!(nhwc.lin_to_cart(4, &dim_size).unwrap(), [0, 1, 0, 1]);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PermutedLayout<const N: usize> {
+    permutation: [usize; N],
+}
+
+impl<const N: usize> PermutedLayout<N> {
+    /**
+    Creates a new [`PermutedLayout`] from the given permutation of the axes (listed from the slowest
+    to the fastest varying axis in memory).
+
+    If `permutation` is not a permutation of `0..N` (i.e. an axis is out of bounds or is listed
+    multiple times), this function returns an error.
+    ```
+    use cart_lin::{IndexError, PermutedLayout};
+
+    assert!(PermutedLayout::new([2, 0, 1]).is_ok());
+
+    // Axis 3 does not exist
+    assert_eq!(PermutedLayout::new([2, 0, 3]), Err(IndexError::InvalidPermutation { axis: 3 }));
+
+    // Axis 0 is listed twice
+    assert_eq!(PermutedLayout::new([2, 0, 0]), Err(IndexError::InvalidPermutation { axis: 0 }));
+    ```
+     */
+    pub fn new(permutation: [usize; N]) -> Result<Self, IndexError> {
+        let mut seen = [false; N];
+        for axis in permutation.iter() {
+            if *axis >= N || seen[*axis] {
+                return Err(IndexError::InvalidPermutation { axis: *axis });
+            }
+            seen[*axis] = true;
+        }
+        return Ok(Self { permutation });
+    }
+
+    /**
+    Returns the layout which is equivalent to [`RowMajor`] (last index changes fastest).
+    ```
+    use cart_lin::PermutedLayout;
+
+    assert_eq!(PermutedLayout::row_major().permutation(), &[0, 1, 2]);
+    ```
+     */
+    pub fn row_major() -> Self {
+        let mut permutation = [0; N];
+        for (axis, p) in permutation.iter_mut().enumerate() {
+            *p = axis;
+        }
+        return Self { permutation };
+    }
+
+    /**
+    Returns the layout which is equivalent to [`ColumnMajor`] (first index changes fastest).
+    ```
+    use cart_lin::PermutedLayout;
+
+    assert_eq!(PermutedLayout::column_major().permutation(), &[2, 1, 0]);
+    ```
+     */
+    pub fn column_major() -> Self {
+        let mut permutation = Self::row_major().permutation;
+        permutation.reverse();
+        return Self { permutation };
+    }
+
+    /**
+    Returns the permutation of the axes, listed from the slowest to the fastest varying axis in memory.
+     */
+    pub fn permutation(&self) -> &[usize; N] {
+        return &self.permutation;
+    }
+
+    /**
+    Convert a cartesian index into a linear index using this layout.
+
+    If any of the cartesian indices are out of bounds or if the shape overflows, this function returns an error.
+    ```
+    use cart_lin::PermutedLayout;
+
+    // 2 x 3 matrix, stored in column-major order:
+    // [0 2 4]
+    // [1 3 5]
+    let layout = PermutedLayout::new([1, 0]).unwrap();
+    assert_eq!(layout.cart_to_lin(&[1, 0], &[2, 3]).unwrap(), 1);
+    assert_eq!(layout.cart_to_lin(&[0, 2], &[2, 3]).unwrap(), 4);
+    assert!(layout.cart_to_lin(&[2, 0], &[2, 3]).is_err()); // Out of bounds
+    ```
+     */
+    pub fn cart_to_lin<T: IndexInt>(
+        &self,
+        indices: &[T; N],
+        dim_size: &[T; N],
+    ) -> Result<T, IndexError<T>> {
+        check_indices(indices, dim_size)?;
+        validate_shape(dim_size)?;
+        return Ok(self.cart_to_lin_unchecked(indices, dim_size));
+    }
+
+    /**
+    Like [`PermutedLayout::cart_to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+     */
+    pub fn cart_to_lin_unchecked<T: IndexInt>(&self, indices: &[T; N], dim_size: &[T; N]) -> T {
+        return encode(
+            self.permutation
+                .iter()
+                .rev()
+                .map(|axis| (&indices[*axis], &dim_size[*axis])),
+        );
+    }
+
+    /**
+    Convert a linear index to a cartesian index using this layout.
+
+    If the linear index is out of bounds (= equal to or larger than the product of all values in
+    `dim_size`) or if the shape overflows, this function returns an error.
+    ```
+    use cart_lin::PermutedLayout;
+
+    // 2 x 3 matrix, stored in column-major order:
+    // [0 2 4]
+    // [1 3 5]
+    let layout = PermutedLayout::new([1, 0]).unwrap();
+    assert_eq!(layout.lin_to_cart(1, &[2, 3]).unwrap(), [1, 0]);
+    assert_eq!(layout.lin_to_cart(4, &[2, 3]).unwrap(), [0, 2]);
+    assert!(layout.lin_to_cart(6, &[2, 3]).is_err()); // Out of bounds
+    ```
+     */
+    pub fn lin_to_cart<T: IndexInt>(
+        &self,
+        index: T,
+        dim_size: &[T; N],
+    ) -> Result<[T; N], IndexError<T>> {
+        check_linear(index, validate_shape(dim_size)?)?;
+        return Ok(self.lin_to_cart_unchecked(index, dim_size));
+    }
+
+    /**
+    Like [`PermutedLayout::lin_to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+     */
+    pub fn lin_to_cart_unchecked<T: IndexInt>(&self, index: T, dim_size: &[T; N]) -> [T; N] {
+        // Decode the index in memory order and scatter the result onto the logical axes afterwards
+        let sizes = self.permutation.map(|axis| dim_size[axis]);
+        let mut digits = [T::ZERO; N];
+        decode(index, digits.iter_mut().rev().zip(sizes.iter().rev()));
+
+        let mut indices = [T::ZERO; N];
+        for (axis, digit) in self.permutation.iter().zip(digits) {
+            indices[*axis] = digit;
+        }
+        return indices;
+    }
+}
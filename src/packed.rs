@@ -0,0 +1,850 @@
+/*!
+Packed storage of triangular and symmetric matrices and of symmetric tensors.
+ */
+
+use crate::error::{IndexError, check_indices, check_linear};
+use crate::index_int::IndexInt;
+use crate::order::Order;
+use crate::traversal::{Traversal, Traverse, impl_traversal_iterator};
+
+/**
+Selects the stored triangle of a [`PackedTriangular`] layout (like the `UPLO` argument of LAPACK).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Uplo {
+    /// The upper triangle (including the diagonal) is stored, i.e. all elements with `row <= column`.
+    Upper,
+    /// The lower triangle (including the diagonal) is stored, i.e. all elements with `row >= column`.
+    Lower,
+}
+
+/**
+Returns `n * (n + 1) / 2` (the number of elements of a triangle with `n` rows) or `None` on overflow.
+ */
+fn checked_triangular<T: IndexInt>(n: T) -> Option<T> {
+    let two = T::ONE + T::ONE;
+    let next = n.checked_add(T::ONE)?;
+    if n % two == T::ZERO {
+        return (n / two).checked_mul(next);
+    } else {
+        return n.checked_mul(next / two);
+    }
+}
+
+/**
+Like [`checked_triangular`], for values which are known to fit into `T`.
+ */
+pub(crate) fn triangular<T: IndexInt>(n: T) -> T {
+    let two = T::ONE + T::ONE;
+    if n % two == T::ZERO {
+        return (n / two) * (n + T::ONE);
+    } else {
+        return n * ((n + T::ONE) / two);
+    }
+}
+
+/**
+Memory layout of a square `n x n` matrix of which only one triangle (including the diagonal) is stored
+contiguously, like the packed storage format of LAPACK (e.g. for triangular or symmetric matrices).
+
+The stored triangle is selected by [`Uplo`], while the memory order of the stored elements is either
+row-major (default) or column-major (see [`PackedTriangular::order`]):
+```
+use cart_lin::{ColumnMajor, PackedTriangular, Uplo};
+
+// Upper triangle of a 3 x 3 matrix in row-major order:
+// [0 1 2]
+// [  3 4]
+// [    5]
+let layout = PackedTriangular::new(3, Uplo::Upper).unwrap();
+assert_eq!(layout.len(), 6);
+assert_eq!(layout.cart_to_lin(&[1, 2]).unwrap(), 4);
+assert_eq!(layout.lin_to_cart(3).unwrap(), [1, 1]);
+assert!(layout.cart_to_lin(&[2, 1]).is_err()); // Not stored
+
+// Upper triangle in column-major order (LAPACK):
+// [0 1 3]
+// [  2 4]
+// [    5]
+let layout = layout.order::<ColumnMajor>();
+assert_eq!(layout.cart_to_lin(&[1, 2]).unwrap(), 4);
+assert_eq!(layout.cart_to_lin(&[0, 2]).unwrap(), 3);
+assert_eq!(layout.lin_to_cart(2).unwrap(), [1, 1]);
+```
+For symmetric matrices, [`PackedTriangular::cart_to_lin_symmetric`] maps both `(i, j)` and `(j, i)`
+onto the same stored element:
+```
+use cart_lin::{PackedTriangular, Uplo};
+
+// Covariance matrix of three variables, lower triangle in row-major order:
+// [0      ]
+// [1 2    ]
+// [3 4 5  ]
+let layout = PackedTriangular::new(3, Uplo::Lower).unwrap();
+let cov = [1.0, 0.5, 2.0, 0.1, 0.3, 3.0];
+assert_eq!(cov[layout.cart_to_lin_symmetric(&[2, 1]).unwrap()], 0.3);
+assert_eq!(cov[layout.cart_to_lin_symmetric(&[1, 2]).unwrap()], 0.3);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedTriangular<T: IndexInt = usize> {
+    n: T,
+    uplo: Uplo,
+    column_major: bool,
+    // Number of stored elements
+    len: T,
+}
+
+impl<T: IndexInt> PackedTriangular<T> {
+    /**
+    Creates a new [`PackedTriangular`] layout of an `n x n` matrix in row-major order.
+
+    If `n` is negative, [`IndexError::NegativeDimension`] is returned. If the number of stored elements
+    `n * (n + 1) / 2` does not fit into the index type, [`IndexError::Overflow`] is returned.
+    ```
+    use cart_lin::{IndexError, PackedTriangular, Uplo};
+
+    assert_eq!(PackedTriangular::<u8>::new(22, Uplo::Upper).unwrap().len(), 253);
+    assert_eq!(PackedTriangular::<u8>::new(23, Uplo::Upper), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn new(n: T, uplo: Uplo) -> Result<Self, IndexError<T>> {
+        if n.is_negative() {
+            return Err(IndexError::NegativeDimension { axis: 0, size: n });
+        }
+        let len = checked_triangular(n).ok_or(IndexError::Overflow)?;
+        return Ok(Self {
+            n,
+            uplo,
+            column_major: false,
+            len,
+        });
+    }
+
+    /**
+    Sets the memory order `O` ([`RowMajor`](crate::RowMajor) or [`ColumnMajor`](crate::ColumnMajor)) of the
+    stored elements.
+    ```
+    use cart_lin::{ColumnMajor, PackedTriangular, Uplo};
+
+    // Lower triangle in column-major order:
+    // [0    ]
+    // [1 3  ]
+    // [2 4 5]
+    let layout = PackedTriangular::new(3, Uplo::Lower)
+        .unwrap()
+        .order::<ColumnMajor>();
+    assert_eq!(layout.cart_to_lin(&[2, 0]).unwrap(), 2);
+    assert_eq!(layout.cart_to_lin(&[1, 1]).unwrap(), 3);
+    ```
+     */
+    pub fn order<O: Order>(mut self) -> Self {
+        // The first axis varies fastest in column-major order
+        let mut strides = [0; 2];
+        O::strides(&[2, 2], &mut strides);
+        self.column_major = strides[0] == 1;
+        return self;
+    }
+
+    /**
+    Returns the number of rows (and columns) of the matrix.
+     */
+    pub fn n(&self) -> T {
+        return self.n;
+    }
+
+    /**
+    Returns the stored triangle.
+     */
+    pub fn uplo(&self) -> Uplo {
+        return self.uplo;
+    }
+
+    /**
+    Returns the number of stored elements `n * (n + 1) / 2`.
+     */
+    pub fn len(&self) -> T {
+        return self.len;
+    }
+
+    /**
+    Returns `true` if the matrix does not contain any elements.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.n == T::ZERO;
+    }
+
+    /**
+    Returns whether the element is part of the stored triangle. The indices must be in bounds.
+     */
+    fn is_stored(&self, [row, column]: [T; 2]) -> bool {
+        match self.uplo {
+            Uplo::Upper => return row <= column,
+            Uplo::Lower => return row >= column,
+        }
+    }
+
+    /**
+    Each combination of triangle and memory order is equivalent to one of the row-major layouts of the
+    transposed matrix: Returns whether the equivalent row-major layout stores the lower triangle.
+     */
+    fn is_lower_row_major(&self) -> bool {
+        return (self.uplo == Uplo::Lower) != self.column_major;
+    }
+
+    /**
+    Returns the linear index of the first stored element of the given row of the equivalent row-major
+    layout.
+     */
+    fn row_start(&self, row: T) -> T {
+        if self.is_lower_row_major() {
+            return triangular(row);
+        } else {
+            return self.len - triangular(self.n - row);
+        }
+    }
+
+    /**
+    Convert a cartesian index `[row, column]` into the index of the element within the packed storage.
+
+    If any of the indices are out of bounds, this function returns [`IndexError::OutOfBounds`]. If the
+    element lies outside of the stored triangle, [`IndexError::NotStored`] is returned.
+    ```
+    use cart_lin::{IndexError, PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Lower).unwrap();
+    assert_eq!(layout.cart_to_lin(&[3, 2]).unwrap(), 8);
+    assert_eq!(
+        layout.cart_to_lin(&[2, 3]),
+        Err(IndexError::NotStored { row: 2, column: 3 })
+    );
+    assert_eq!(
+        layout.cart_to_lin(&[4, 0]),
+        Err(IndexError::OutOfBounds { axis: 0, index: 4, bound: 4 })
+    );
+    ```
+     */
+    pub fn cart_to_lin(&self, indices: &[T; 2]) -> Result<T, IndexError<T>> {
+        check_indices(indices, &[self.n, self.n])?;
+        if !self.is_stored(*indices) {
+            let [row, column] = *indices;
+            return Err(IndexError::NotStored { row, column });
+        }
+        return Ok(self.cart_to_lin_unchecked(indices));
+    }
+
+    /**
+    Like [`PackedTriangular::cart_to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might
+    be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::{PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Lower).unwrap();
+    assert_eq!(layout.cart_to_lin_unchecked(&[3, 2]), 8);
+    ```
+     */
+    pub fn cart_to_lin_unchecked(&self, indices: &[T; 2]) -> T {
+        let [row, column] = self.transpose_if_column_major(*indices);
+        if self.is_lower_row_major() {
+            return self.row_start(row) + column;
+        } else {
+            return self.row_start(row) + (column - row);
+        }
+    }
+
+    /**
+    Convert a cartesian index `[row, column]` of a symmetric matrix into the index of the element within
+    the packed storage. Elements outside of the stored triangle are mirrored at the diagonal, i.e. both
+    `[i, j]` and `[j, i]` map onto the same index.
+
+    If any of the indices are out of bounds, this function returns [`IndexError::OutOfBounds`].
+    ```
+    use cart_lin::{PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Upper).unwrap();
+    assert_eq!(layout.cart_to_lin_symmetric(&[0, 3]).unwrap(), 3);
+    assert_eq!(layout.cart_to_lin_symmetric(&[3, 0]).unwrap(), 3);
+    ```
+     */
+    pub fn cart_to_lin_symmetric(&self, indices: &[T; 2]) -> Result<T, IndexError<T>> {
+        check_indices(indices, &[self.n, self.n])?;
+        return Ok(self.cart_to_lin_symmetric_unchecked(indices));
+    }
+
+    /**
+    Like [`PackedTriangular::cart_to_lin_symmetric`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might
+    be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::{PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Upper).unwrap();
+    assert_eq!(layout.cart_to_lin_symmetric_unchecked(&[3, 0]), 3);
+    ```
+     */
+    pub fn cart_to_lin_symmetric_unchecked(&self, indices: &[T; 2]) -> T {
+        if self.is_stored(*indices) {
+            return self.cart_to_lin_unchecked(indices);
+        } else {
+            return self.cart_to_lin_unchecked(&[indices[1], indices[0]]);
+        }
+    }
+
+    /**
+    Convert the index of an element within the packed storage into its cartesian index `[row, column]`.
+
+    If the index is negative or not smaller than the number of stored elements, this function returns
+    [`IndexError::LinearOutOfRange`].
+    ```
+    use cart_lin::{IndexError, PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Lower).unwrap();
+    assert_eq!(layout.lin_to_cart(8).unwrap(), [3, 2]);
+    assert_eq!(
+        layout.lin_to_cart(10),
+        Err(IndexError::LinearOutOfRange { index: 10, len: 10 })
+    );
+    ```
+     */
+    pub fn lin_to_cart(&self, index: T) -> Result<[T; 2], IndexError<T>> {
+        check_linear(index, self.len)?;
+        return Ok(self.lin_to_cart_unchecked(index));
+    }
+
+    /**
+    Like [`PackedTriangular::lin_to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the cartesian index received from this
+    function might be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::{PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(4, Uplo::Lower).unwrap();
+    assert_eq!(layout.lin_to_cart_unchecked(8), [3, 2]);
+    ```
+     */
+    pub fn lin_to_cart_unchecked(&self, index: T) -> [T; 2] {
+        return self.transpose_if_column_major(self.lin_to_row_major_cart(index));
+    }
+
+    /**
+    Returns the cartesian index of the element with the given linear index within the equivalent row-major
+    layout.
+     */
+    fn lin_to_row_major_cart(&self, index: T) -> [T; 2] {
+        if self.n == T::ZERO {
+            return [T::ZERO, T::ZERO];
+        }
+
+        // Binary search for the last row starting at or before the index
+        let two = T::ONE + T::ONE;
+        let mut lowest = T::ZERO;
+        let mut highest = self.n - T::ONE;
+        while lowest < highest {
+            let middle = lowest + (highest - lowest + T::ONE) / two;
+            if self.row_start(middle) <= index {
+                lowest = middle;
+            } else {
+                highest = middle - T::ONE;
+            }
+        }
+        let row = lowest;
+        if self.is_lower_row_major() {
+            return [row, index - self.row_start(row)];
+        } else {
+            return [row, index - self.row_start(row) + row];
+        }
+    }
+
+    /**
+    Converts between cartesian indices of the matrix and of the equivalent row-major layout.
+     */
+    fn transpose_if_column_major(&self, [row, column]: [T; 2]) -> [T; 2] {
+        if self.column_major {
+            return [column, row];
+        } else {
+            return [row, column];
+        }
+    }
+
+    /**
+    Returns an iterator over the cartesian indices of all stored elements in memory order, i.e. the `k`-th
+    element yielded by the iterator is stored at index `k`.
+    ```
+    use cart_lin::{ColumnMajor, PackedTriangular, Uplo};
+
+    let layout = PackedTriangular::new(3, Uplo::Upper)
+        .unwrap()
+        .order::<ColumnMajor>();
+    assert_eq!(
+        layout.indices().collect::<Vec<_>>(),
+        [[0, 0], [0, 1], [1, 1], [0, 2], [1, 2], [2, 2]]
+    );
+    ```
+     */
+    pub fn indices(&self) -> TriangularIndices<T> {
+        return TriangularIndices {
+            traversal: Traversal::new(*self, self.len, self.lin_to_row_major_cart(T::ZERO)),
+        };
+    }
+}
+
+/**
+An iterator over the cartesian indices of the stored elements of a [`PackedTriangular`] layout in memory
+order.
+
+This struct is created by [`PackedTriangular::indices`], see its documentation for more. It supports
+random access via [`Iterator::nth`] and traversal from the back:
+```
+use cart_lin::{PackedTriangular, Uplo};
+
+let mut cartiter = PackedTriangular::new(4, Uplo::Lower).unwrap().indices();
+assert_eq!(cartiter.len(), 10);
+assert_eq!(cartiter.nth(4), Some([2, 1]));
+assert_eq!(cartiter.next(), Some([2, 2]));
+assert_eq!(cartiter.next_back(), Some([3, 3]));
+assert_eq!(cartiter.len(), 3);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct TriangularIndices<T: IndexInt = usize> {
+    traversal: Traversal<PackedTriangular<T>, T>,
+}
+
+// The cursor is the cartesian index of the equivalent row-major layout
+impl<T: IndexInt> Traverse<T> for PackedTriangular<T> {
+    type Cursor = [T; 2];
+    type Item = [T; 2];
+
+    fn cursor_at(&self, position: T) -> [T; 2] {
+        return self.lin_to_row_major_cart(position);
+    }
+
+    fn advance(&self, cart: &mut [T; 2]) {
+        let [row, column] = cart;
+        if self.is_lower_row_major() {
+            if *column < *row {
+                *column += T::ONE;
+            } else {
+                *row += T::ONE;
+                *column = T::ZERO;
+            }
+        } else if *column + T::ONE < self.n {
+            *column += T::ONE;
+        } else {
+            *row += T::ONE;
+            *column = *row;
+        }
+    }
+
+    fn retreat(&self, cart: &mut [T; 2]) {
+        let [row, column] = cart;
+        if self.is_lower_row_major() {
+            if *column > T::ZERO {
+                *column = *column - T::ONE;
+            } else {
+                *row = *row - T::ONE;
+                *column = *row;
+            }
+        } else if *column > *row {
+            *column = *column - T::ONE;
+        } else {
+            *row = *row - T::ONE;
+            *column = self.n - T::ONE;
+        }
+    }
+
+    fn item(&self, cart: &[T; 2]) -> [T; 2] {
+        return self.transpose_if_column_major(*cart);
+    }
+}
+
+impl_traversal_iterator!([T: IndexInt] TriangularIndices<T>, T, [T; 2]);
+
+/**
+Returns the greatest common divisor of two non-negative integers.
+ */
+fn gcd<T: IndexInt>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    return a;
+}
+
+/**
+Returns the number of sorted tuples of length `r` with values in `0..m`, i.e. the number of multisets
+`(m + r - 1)! / (r! * (m - 1)!)`, or `None` if it does not fit into `T`.
+ */
+fn checked_multisets<T: IndexInt>(m: T, r: usize) -> Option<T> {
+    if r == 0 || m == T::ONE {
+        return Some(T::ONE);
+    }
+    if m == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // After step t, res is the number of sorted tuples of length t, which is at least t + 1 for m >= 2
+    let mut res = T::ONE;
+    let mut t = T::ZERO;
+    for _ in 0..r {
+        t += T::ONE;
+        // res * (m - 1 + t) is divisible by t. Dividing by the common divisor first ensures that the
+        // product only overflows if the result does.
+        let divisor = gcd(res, t);
+        res = (res / divisor).checked_mul((m - T::ONE).checked_add(t)? / (t / divisor))?;
+    }
+    return Some(res);
+}
+
+/**
+Like [`checked_multisets`], for values whose result is known to fit into `T`.
+ */
+fn multisets<T: IndexInt>(m: T, r: usize) -> T {
+    if r == 0 || m == T::ONE {
+        return T::ONE;
+    }
+    if m == T::ZERO {
+        return T::ZERO;
+    }
+    let mut res = T::ONE;
+    let mut t = T::ZERO;
+    for _ in 0..r {
+        t += T::ONE;
+        let divisor = gcd(res, t);
+        res = (res / divisor) * ((m - T::ONE + t) / (t / divisor));
+    }
+    return res;
+}
+
+/**
+Memory layout of a fully symmetric tensor of rank `N` with `n` elements along each axis, which only
+stores one representative of all permutations of each cartesian index.
+
+The representative of a cartesian index is its sorted permutation `i_1 <= i_2 <= ... <= i_N` (see
+[`PackedSymmetric::canonicalize`]). The representatives are stored in lexicographic order, i.e. like
+[`RowMajor`](crate::RowMajor) the last index changes fastest. For `N = 2`, this is the same layout as
+[`PackedTriangular`] storing the upper triangle in row-major order.
+```
+use cart_lin::{IndexError, PackedSymmetric};
+
+// Symmetric 3 x 3 x 3 tensor with 10 distinct elements
+let layout = PackedSymmetric::new(3).unwrap();
+assert_eq!(layout.len(), 10);
+assert_eq!(layout.cart_to_lin(&[0, 0, 0]).unwrap(), 0);
+assert_eq!(layout.cart_to_lin(&[0, 1, 2]).unwrap(), 4);
+assert_eq!(layout.cart_to_lin(&[2, 2, 2]).unwrap(), 9);
+assert_eq!(layout.lin_to_cart(4).unwrap(), [0, 1, 2]);
+
+// Unsorted cartesian indices are not representatives ...
+assert_eq!(layout.cart_to_lin(&[2, 1, 0]), Err(IndexError::NotSorted { axis: 1 }));
+
+// ... but can be mapped onto their representative
+assert_eq!(layout.canonicalize(&[2, 1, 0]), [0, 1, 2]);
+assert_eq!(layout.cart_to_lin_symmetric(&[2, 1, 0]).unwrap(), 4);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedSymmetric<const N: usize, T: IndexInt = usize> {
+    n: T,
+    // Number of stored elements
+    len: T,
+}
+
+impl<const N: usize, T: IndexInt> PackedSymmetric<N, T> {
+    /**
+    Creates a new [`PackedSymmetric`] layout of a tensor with `n` elements along each axis.
+
+    If `n` is negative, [`IndexError::NegativeDimension`] is returned. If the number of stored elements
+    (the number of sorted cartesian indices) does not fit into the index type, [`IndexError::Overflow`]
+    is returned.
+    ```
+    use cart_lin::{IndexError, PackedSymmetric};
+
+    // Elasticity tensor in three dimensions
+    assert_eq!(PackedSymmetric::<4>::new(3).unwrap().len(), 15);
+
+    assert_eq!(PackedSymmetric::<4, u8>::new(7).unwrap().len(), 210);
+    assert_eq!(PackedSymmetric::<4, u8>::new(8).unwrap_err(), IndexError::Overflow);
+    ```
+     */
+    pub fn new(n: T) -> Result<Self, IndexError<T>> {
+        if n.is_negative() {
+            return Err(IndexError::NegativeDimension { axis: 0, size: n });
+        }
+        let len = checked_multisets(n, N).ok_or(IndexError::Overflow)?;
+        return Ok(Self { n, len });
+    }
+
+    /**
+    Returns the number of elements along each axis.
+     */
+    pub fn n(&self) -> T {
+        return self.n;
+    }
+
+    /**
+    Returns the number of stored elements.
+     */
+    pub fn len(&self) -> T {
+        return self.len;
+    }
+
+    /**
+    Returns `true` if the tensor does not contain any elements.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == T::ZERO;
+    }
+
+    /**
+    Returns the representative of a cartesian index, i.e. its indices sorted in ascending order.
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::new(4).unwrap();
+    assert_eq!(layout.canonicalize(&[3, 0, 2, 0]), [0, 0, 2, 3]);
+    ```
+     */
+    pub fn canonicalize(&self, indices: &[T; N]) -> [T; N] {
+        let mut res = *indices;
+        res.sort_unstable();
+        return res;
+    }
+
+    /**
+    Convert the representative of a cartesian index into the index of the element within the packed
+    storage.
+
+    If any of the indices are out of bounds, this function returns [`IndexError::OutOfBounds`]. If the
+    cartesian index is not sorted in ascending order, [`IndexError::NotSorted`] is returned.
+    ```
+    use cart_lin::{IndexError, PackedSymmetric};
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.cart_to_lin(&[1, 1, 2]).unwrap(), 7);
+    assert_eq!(layout.cart_to_lin(&[1, 2, 1]), Err(IndexError::NotSorted { axis: 2 }));
+    assert_eq!(
+        layout.cart_to_lin(&[1, 1, 3]),
+        Err(IndexError::OutOfBounds { axis: 2, index: 3, bound: 3 })
+    );
+    ```
+     */
+    pub fn cart_to_lin(&self, indices: &[T; N]) -> Result<T, IndexError<T>> {
+        check_indices(indices, &[self.n; N])?;
+        for (axis, pair) in indices.windows(2).enumerate() {
+            if pair[1] < pair[0] {
+                return Err(IndexError::NotSorted { axis: axis + 1 });
+            }
+        }
+        return Ok(self.cart_to_lin_unchecked(indices));
+    }
+
+    /**
+    Like [`PackedSymmetric::cart_to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might
+    be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.cart_to_lin_unchecked(&[1, 1, 2]), 7);
+    ```
+     */
+    pub fn cart_to_lin_unchecked(&self, indices: &[T; N]) -> T {
+        // For each axis, count the representatives whose index of this axis is smaller (with all previous
+        // indices being equal)
+        let mut lin = T::ZERO;
+        let mut previous = T::ZERO;
+        for (axis, index) in indices.iter().enumerate() {
+            let rank = N - axis;
+            lin += multisets(self.n - previous, rank) - multisets(self.n - *index, rank);
+            previous = *index;
+        }
+        return lin;
+    }
+
+    /**
+    Convert any cartesian index into the index of its representative within the packed storage (see
+    [`PackedSymmetric::canonicalize`]), i.e. all permutations of a cartesian index map onto the same index.
+
+    If any of the indices are out of bounds, this function returns [`IndexError::OutOfBounds`].
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.cart_to_lin_symmetric(&[2, 1, 1]).unwrap(), 7);
+    assert_eq!(layout.cart_to_lin_symmetric(&[1, 2, 1]).unwrap(), 7);
+    ```
+     */
+    pub fn cart_to_lin_symmetric(&self, indices: &[T; N]) -> Result<T, IndexError<T>> {
+        check_indices(indices, &[self.n; N])?;
+        return Ok(self.cart_to_lin_symmetric_unchecked(indices));
+    }
+
+    /**
+    Like [`PackedSymmetric::cart_to_lin_symmetric`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might
+    be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.cart_to_lin_symmetric_unchecked(&[2, 1, 1]), 7);
+    ```
+     */
+    pub fn cart_to_lin_symmetric_unchecked(&self, indices: &[T; N]) -> T {
+        return self.cart_to_lin_unchecked(&self.canonicalize(indices));
+    }
+
+    /**
+    Convert the index of an element within the packed storage into its representative.
+
+    If the index is negative or not smaller than the number of stored elements, this function returns
+    [`IndexError::LinearOutOfRange`].
+    ```
+    use cart_lin::{IndexError, PackedSymmetric};
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.lin_to_cart(7).unwrap(), [1, 1, 2]);
+    assert_eq!(
+        layout.lin_to_cart(10),
+        Err(IndexError::LinearOutOfRange { index: 10, len: 10 })
+    );
+    ```
+     */
+    pub fn lin_to_cart(&self, index: T) -> Result<[T; N], IndexError<T>> {
+        check_linear(index, self.len)?;
+        return Ok(self.lin_to_cart_unchecked(index));
+    }
+
+    /**
+    Like [`PackedSymmetric::lin_to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the cartesian index received from this
+    function might be invalid. Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::new(3).unwrap();
+    assert_eq!(layout.lin_to_cart_unchecked(7), [1, 1, 2]);
+    ```
+     */
+    pub fn lin_to_cart_unchecked(&self, index: T) -> [T; N] {
+        let mut res = [T::ZERO; N];
+        if self.n == T::ZERO {
+            return res;
+        }
+
+        let two = T::ONE + T::ONE;
+        let mut rest = index;
+        let mut previous = T::ZERO;
+        for (axis, cart_index) in res.iter_mut().enumerate() {
+            // Binary search for the largest index of this axis for which the number of representatives
+            // with a smaller index (and the same previous indices) does not exceed the rest
+            let rank = N - axis;
+            let total = multisets(self.n - previous, rank);
+            let mut lowest = previous;
+            let mut highest = self.n - T::ONE;
+            while lowest < highest {
+                let middle = lowest + (highest - lowest + T::ONE) / two;
+                if total - multisets(self.n - middle, rank) <= rest {
+                    lowest = middle;
+                } else {
+                    highest = middle - T::ONE;
+                }
+            }
+            rest = rest - (total - multisets(self.n - lowest, rank));
+            *cart_index = lowest;
+            previous = lowest;
+        }
+        return res;
+    }
+
+    /**
+    Returns an iterator over all representatives in memory order, i.e. the `k`-th cartesian index yielded
+    by the iterator is stored at index `k`.
+    ```
+    use cart_lin::PackedSymmetric;
+
+    let layout = PackedSymmetric::<3>::new(2).unwrap();
+    assert_eq!(
+        layout.indices().collect::<Vec<_>>(),
+        [[0, 0, 0], [0, 0, 1], [0, 1, 1], [1, 1, 1]]
+    );
+    ```
+     */
+    pub fn indices(&self) -> SymmetricIndices<N, T> {
+        return SymmetricIndices {
+            traversal: Traversal::new(*self, self.len, [T::ZERO; N]),
+        };
+    }
+}
+
+/**
+An iterator over the representatives of a [`PackedSymmetric`] layout in memory order.
+
+This struct is created by [`PackedSymmetric::indices`], see its documentation for more. It supports
+random access via [`Iterator::nth`] and traversal from the back:
+```
+use cart_lin::PackedSymmetric;
+
+let mut cartiter = PackedSymmetric::<3>::new(3).unwrap().indices();
+assert_eq!(cartiter.len(), 10);
+assert_eq!(cartiter.nth(4), Some([0, 1, 2]));
+assert_eq!(cartiter.next(), Some([0, 2, 2]));
+assert_eq!(cartiter.next_back(), Some([2, 2, 2]));
+assert_eq!(cartiter.next_back(), Some([1, 2, 2]));
+assert_eq!(cartiter.len(), 2);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SymmetricIndices<const N: usize, T: IndexInt = usize> {
+    traversal: Traversal<PackedSymmetric<N, T>, T>,
+}
+
+// The cursor is the representative itself
+impl<const N: usize, T: IndexInt> Traverse<T> for PackedSymmetric<N, T> {
+    type Cursor = [T; N];
+    type Item = [T; N];
+
+    fn cursor_at(&self, position: T) -> [T; N] {
+        return self.lin_to_cart_unchecked(position);
+    }
+
+    fn advance(&self, cart: &mut [T; N]) {
+        // Increment the last index which is not at its maximum and reset all following indices to it
+        if let Some(axis) = cart.iter().rposition(|index| *index + T::ONE < self.n) {
+            let index = cart[axis] + T::ONE;
+            for following in cart[axis..].iter_mut() {
+                *following = index;
+            }
+        }
+    }
+
+    fn retreat(&self, cart: &mut [T; N]) {
+        // Decrement the last index which is larger than the previous one (or larger than zero for the
+        // first axis) and set all following indices to their maximum
+        let decrementable = (0..N).rev().find(|axis| match axis.checked_sub(1) {
+            Some(previous) => cart[*axis] > cart[previous],
+            None => cart[*axis] > T::ZERO,
+        });
+        if let Some(axis) = decrementable {
+            cart[axis] = cart[axis] - T::ONE;
+            for following in cart[axis + 1..].iter_mut() {
+                *following = self.n - T::ONE;
+            }
+        }
+    }
+
+    fn item(&self, cart: &[T; N]) -> [T; N] {
+        return *cart;
+    }
+}
+
+impl_traversal_iterator!([const N: usize, T: IndexInt] SymmetricIndices<N, T>, T, [T; N]);
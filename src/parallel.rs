@@ -0,0 +1,112 @@
+/*!
+Parallel iteration over cartesian indices with [rayon](https://crates.io/crates/rayon).
+ */
+
+use rayon::iter::plumbing::{Consumer, Producer, ProducerCallback, UnindexedConsumer, bridge};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::cartesian_indices::CartesianIndices;
+use crate::index_int::IndexInt;
+
+/**
+A parallel iterator over the cartesian indices of a [`CartesianIndices`] (requires the `rayon` feature).
+
+This struct is created by calling [`IntoParallelIterator::into_par_iter`] on a [`CartesianIndices`]. Since
+[`CartesianIndices`] is backed by a linear position, it can be split at any index in O(N) without
+collecting the indices first. The parallel iterator yields the same elements in the same order as its
+sequential counterpart, including sub-boxes, steps, custom traversal orders and reversed axes:
+```
+use cart_lin::CartesianIndices;
+use rayon::prelude::*;
+
+let dim_size = [20, 30, 40];
+let cells: Vec<[usize; 3]> = CartesianIndices::new(dim_size).into_par_iter().collect();
+assert_eq!(cells.len(), 24_000);
+assert_eq!(cells[1], [0, 0, 1]);
+
+// Indexed parallel iterator adapters are supported as well
+let interior_sum: usize = CartesianIndices::from_bounds([[1, 19], [1, 29], [1, 39]])
+    .unwrap()
+    .into_par_iter()
+    .enumerate()
+    .map(|(pos, [i, j, k])| pos * (i + j + k))
+    .sum();
+```
+Like [`ExactSizeIterator`], parallel iteration is only available for index types whose number of elements
+is guaranteed to fit into an `usize` on the current target.
+ */
+#[derive(Debug, Clone)]
+pub struct ParCartesianIndices<const N: usize, T: IndexInt = usize> {
+    iter: CartesianIndices<N, T>,
+}
+
+impl<const N: usize, T: IndexInt + Send> IntoParallelIterator for CartesianIndices<N, T>
+where
+    CartesianIndices<N, T>: ExactSizeIterator<Item = [T; N]>,
+{
+    type Iter = ParCartesianIndices<N, T>;
+    type Item = [T; N];
+
+    fn into_par_iter(self) -> Self::Iter {
+        return ParCartesianIndices { iter: self };
+    }
+}
+
+impl<const N: usize, T: IndexInt + Send> ParallelIterator for ParCartesianIndices<N, T>
+where
+    CartesianIndices<N, T>: ExactSizeIterator<Item = [T; N]>,
+{
+    type Item = [T; N];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        return bridge(self, consumer);
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        return Some(self.iter.len());
+    }
+}
+
+impl<const N: usize, T: IndexInt + Send> IndexedParallelIterator for ParCartesianIndices<N, T>
+where
+    CartesianIndices<N, T>: ExactSizeIterator<Item = [T; N]>,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        return bridge(self, consumer);
+    }
+
+    fn len(&self) -> usize {
+        return self.iter.len();
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        return callback.callback(CartesianIndicesProducer { iter: self.iter });
+    }
+}
+
+/**
+Splittable part of a [`ParCartesianIndices`], which is handed to the rayon worker threads.
+ */
+struct CartesianIndicesProducer<const N: usize, T: IndexInt> {
+    iter: CartesianIndices<N, T>,
+}
+
+impl<const N: usize, T: IndexInt + Send> Producer for CartesianIndicesProducer<N, T>
+where
+    CartesianIndices<N, T>: ExactSizeIterator<Item = [T; N]>,
+{
+    type Item = [T; N];
+    type IntoIter = CartesianIndices<N, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter;
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        return (Self { iter: left }, Self { iter: right });
+    }
+}
@@ -0,0 +1,441 @@
+/*!
+Shapes with precomputed strides for fast repeated conversion between linear and cartesian indices.
+ */
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec};
+
+use crate::error::{IndexError, check_indices, check_linear, validate_shape};
+use crate::order::{Order, RowMajor};
+
+/**
+A shape (size of each dimension) with precomputed strides and total number of elements.
+
+The free functions such as [`cart_to_lin`](crate::cart_to_lin) and [`lin_to_cart`](crate::lin_to_cart)
+recompute the strides respectively the total number of elements on each call. If the same shape is used
+for many conversions (e.g. in a hot loop), a [`Shape`] avoids this overhead by calculating these values
+only once on construction. The memory order is defined by the type parameter `O` (row-major by default,
+see [`Shape::from_order`]).
+```
+use cart_lin::Shape;
+
+// 2 x 3 x 4 matrix
+let shape = Shape::new([2, 3, 4]);
+assert_eq!(shape.len(), 24);
+assert_eq!(shape.strides(), &[12, 4, 1]);
+
+assert_eq!(shape.to_lin(&[1, 2, 3]).unwrap(), 23);
+assert_eq!(shape.to_cart(23).unwrap(), [1, 2, 3]);
+assert!(shape.to_cart(24).is_err()); // Out of bounds
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape<const N: usize, O: Order = RowMajor> {
+    dim_size: [usize; N],
+    strides: [usize; N],
+    len: usize,
+    order: PhantomData<O>,
+}
+
+impl<const N: usize> Shape<N> {
+    /**
+    Creates a new [`Shape`] in row-major order from the given dimension sizes.
+    ```
+    use cart_lin::Shape;
+
+    let shape = Shape::new([2, 3]);
+    assert_eq!(shape.dim_size(), &[2, 3]);
+    assert_eq!(shape.strides(), &[3, 1]);
+    assert_eq!(shape.len(), 6);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`Shape::try_new`] for untrusted input.
+     */
+    pub fn new(dim_size: [usize; N]) -> Self {
+        return Self::from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Like [`Shape::new`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{IndexError, Shape};
+
+    assert_eq!(Shape::try_new([2, 3]).unwrap().len(), 6);
+    assert_eq!(Shape::try_new([usize::MAX, 2]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_new(dim_size: [usize; N]) -> Result<Self, IndexError> {
+        return Self::try_from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Creates a new [`Shape`] in the memory order `O` from the given dimension sizes.
+    ```
+    use cart_lin::{ColumnMajor, Shape};
+
+    let shape = Shape::from_order::<ColumnMajor>([2, 3]);
+    assert_eq!(shape.strides(), &[1, 2]);
+    assert_eq!(shape.to_lin(&[1, 2]).unwrap(), 5);
+    assert_eq!(shape.to_cart(1).unwrap(), [1, 0]);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`Shape::try_from_order`] for untrusted input.
+     */
+    pub fn from_order<O: Order>(dim_size: [usize; N]) -> Shape<N, O> {
+        match Self::try_from_order::<O>(dim_size) {
+            Ok(shape) => return shape,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /**
+    Like [`Shape::from_order`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{ColumnMajor, IndexError, Shape};
+
+    assert_eq!(Shape::try_from_order::<ColumnMajor>([2, 3]).unwrap().strides(), &[1, 2]);
+    assert_eq!(Shape::try_from_order::<ColumnMajor>([2, usize::MAX]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_from_order<O: Order>(dim_size: [usize; N]) -> Result<Shape<N, O>, IndexError> {
+        let len = validate_shape(&dim_size)?;
+        let mut strides = [0; N];
+        O::strides(&dim_size, &mut strides);
+        return Ok(Shape {
+            dim_size,
+            strides,
+            len,
+            order: PhantomData,
+        });
+    }
+}
+
+impl<const N: usize, O: Order> Shape<N, O> {
+    /**
+    Returns the size of each dimension.
+     */
+    pub fn dim_size(&self) -> &[usize; N] {
+        return &self.dim_size;
+    }
+
+    /**
+    Returns the stride of each axis (the distance between the linear indices of two neighboring
+    elements along the axis).
+     */
+    pub fn strides(&self) -> &[usize; N] {
+        return &self.strides;
+    }
+
+    /**
+    Returns the total number of elements.
+     */
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /**
+    Returns `true` if the shape does not contain any elements (i.e. at least one dimension has size zero).
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Convert a cartesian index into a linear index.
+
+    If any of the cartesian indices are out of bounds, this function returns an error.
+    See also [`cart_to_lin`](crate::cart_to_lin).
+    ```
+    use cart_lin::Shape;
+
+    let shape = Shape::new([2, 5]);
+    assert_eq!(shape.to_lin(&[1, 4]).unwrap(), 9);
+    assert!(shape.to_lin(&[1, 5]).is_err()); // Out of bounds
+    ```
+     */
+    pub fn to_lin(&self, indices: &[usize; N]) -> Result<usize, IndexError> {
+        check_indices(indices, &self.dim_size)?;
+        return Ok(self.to_lin_unchecked(indices));
+    }
+
+    /**
+    Like [`Shape::to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::Shape;
+
+    let shape = Shape::new([2, 5]);
+    assert_eq!(shape.to_lin_unchecked(&[1, 4]), 9);
+    assert_eq!(shape.to_lin_unchecked(&[1, 5]), 10); // Nonsensical value
+    ```
+     */
+    pub fn to_lin_unchecked(&self, indices: &[usize; N]) -> usize {
+        let mut index = 0;
+        for (cart_index, stride) in indices.iter().zip(self.strides.iter()) {
+            index += cart_index * stride;
+        }
+        return index;
+    }
+
+    /**
+    Convert a linear index to a cartesian index.
+
+    If the linear index is out of bounds (= equal to or larger than [`Shape::len`]), this function
+    returns an error. See also [`lin_to_cart`](crate::lin_to_cart).
+    ```
+    use cart_lin::Shape;
+
+    let shape = Shape::new([2, 3]);
+    assert_eq!(shape.to_cart(4).unwrap(), [1, 1]);
+    assert!(shape.to_cart(6).is_err()); // Out of bounds
+    ```
+     */
+    pub fn to_cart(&self, index: usize) -> Result<[usize; N], IndexError> {
+        check_linear(index, self.len)?;
+        return Ok(self.to_cart_unchecked(index));
+    }
+
+    /**
+    Like [`Shape::to_cart`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+    ```
+    use cart_lin::Shape;
+
+    let shape = Shape::new([2, 3]);
+    assert_eq!(shape.to_cart_unchecked(4), [1, 1]);
+    assert_eq!(shape.to_cart_unchecked(6), [0, 0]); // Nonsensical value (wrapping around)
+    ```
+     */
+    pub fn to_cart_unchecked(&self, index: usize) -> [usize; N] {
+        return O::lin_to_cart_unchecked(index, &self.dim_size);
+    }
+}
+
+/**
+Like [`Shape`], but for a number of dimensions which is only known at runtime.
+
+The dimension sizes and strides are stored on the heap, hence this type requires the `alloc` feature
+(enabled by default via the `std` feature).
+```
+use cart_lin::ShapeDyn;
+
+// Number of dimensions read from e.g. a file header
+let dim_size = vec![2, 3, 4];
+let shape = ShapeDyn::new(&dim_size);
+assert_eq!(shape.len(), 24);
+assert_eq!(shape.strides(), &[12, 4, 1]);
+
+assert_eq!(shape.to_lin(&[1, 2, 3]).unwrap(), 23);
+
+let mut indices = vec![0; shape.ndim()];
+assert!(shape.to_cart(23, &mut indices).is_ok());
+assert_eq!(indices, [1, 2, 3]);
+```
+ */
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShapeDyn<O: Order = RowMajor> {
+    dim_size: Box<[usize]>,
+    strides: Box<[usize]>,
+    len: usize,
+    order: PhantomData<O>,
+}
+
+#[cfg(feature = "alloc")]
+impl ShapeDyn {
+    /**
+    Creates a new [`ShapeDyn`] in row-major order from the given dimension sizes.
+    ```
+    use cart_lin::ShapeDyn;
+
+    let shape = ShapeDyn::new(&[2, 3]);
+    assert_eq!(shape.dim_size(), &[2, 3]);
+    assert_eq!(shape.strides(), &[3, 1]);
+    assert_eq!(shape.len(), 6);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`ShapeDyn::try_new`] for untrusted input.
+     */
+    pub fn new(dim_size: &[usize]) -> Self {
+        return Self::from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Like [`ShapeDyn::new`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{IndexError, ShapeDyn};
+
+    // Dimension sizes read from e.g. an untrusted file header
+    let dim_size = vec![usize::MAX, 2];
+    assert_eq!(ShapeDyn::try_new(&dim_size), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_new(dim_size: &[usize]) -> Result<Self, IndexError> {
+        return Self::try_from_order::<RowMajor>(dim_size);
+    }
+
+    /**
+    Creates a new [`ShapeDyn`] in the memory order `O` from the given dimension sizes.
+    ```
+    use cart_lin::{ColumnMajor, ShapeDyn};
+
+    let shape = ShapeDyn::from_order::<ColumnMajor>(&[2, 3]);
+    assert_eq!(shape.strides(), &[1, 2]);
+    assert_eq!(shape.to_lin(&[1, 2]).unwrap(), 5);
+    ```
+
+    # Panics
+
+    Panics if the total number of elements overflows an `usize`. Use [`ShapeDyn::try_from_order`] for untrusted input.
+     */
+    pub fn from_order<O: Order>(dim_size: &[usize]) -> ShapeDyn<O> {
+        match Self::try_from_order::<O>(dim_size) {
+            Ok(shape) => return shape,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /**
+    Like [`ShapeDyn::from_order`], but returns an error instead of panicking if the shape overflows
+    (see [`validate_shape`](crate::validate_shape)).
+    ```
+    use cart_lin::{ColumnMajor, IndexError, ShapeDyn};
+
+    assert!(ShapeDyn::try_from_order::<ColumnMajor>(&[2, 3]).is_ok());
+    assert_eq!(ShapeDyn::try_from_order::<ColumnMajor>(&[2, usize::MAX]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn try_from_order<O: Order>(dim_size: &[usize]) -> Result<ShapeDyn<O>, IndexError> {
+        let len = validate_shape(dim_size)?;
+        let mut strides = vec![0; dim_size.len()];
+        O::strides(dim_size, &mut strides);
+        return Ok(ShapeDyn {
+            dim_size: dim_size.into(),
+            strides: strides.into_boxed_slice(),
+            len,
+            order: PhantomData,
+        });
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<O: Order> ShapeDyn<O> {
+    /**
+    Returns the number of dimensions.
+     */
+    pub fn ndim(&self) -> usize {
+        return self.dim_size.len();
+    }
+
+    /**
+    Returns the size of each dimension.
+     */
+    pub fn dim_size(&self) -> &[usize] {
+        return &self.dim_size;
+    }
+
+    /**
+    Returns the stride of each axis (the distance between the linear indices of two neighboring
+    elements along the axis).
+     */
+    pub fn strides(&self) -> &[usize] {
+        return &self.strides;
+    }
+
+    /**
+    Returns the total number of elements.
+     */
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /**
+    Returns `true` if the shape does not contain any elements (i.e. at least one dimension has size zero).
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Convert a cartesian index into a linear index.
+
+    If the length of the cartesian index is not equal to the number of dimensions or if any of the
+    cartesian indices are out of bounds, this function returns an error. See also [`cart_to_lin`](crate::cart_to_lin).
+    ```
+    use cart_lin::ShapeDyn;
+
+    let shape = ShapeDyn::new(&[2, 5]);
+    assert_eq!(shape.to_lin(&[1, 4]).unwrap(), 9);
+    assert!(shape.to_lin(&[1, 5]).is_err()); // Out of bounds
+    assert!(shape.to_lin(&[1]).is_err()); // Wrong number of dimensions
+    ```
+     */
+    pub fn to_lin(&self, indices: &[usize]) -> Result<usize, IndexError> {
+        check_indices(indices, &self.dim_size)?;
+        return Ok(self.to_lin_unchecked(indices));
+    }
+
+    /**
+    Like [`ShapeDyn::to_lin`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the index received from this function might be invalid.
+    Using such an invalid index may cause an out-of-bounds read.
+     */
+    pub fn to_lin_unchecked(&self, indices: &[usize]) -> usize {
+        let mut index = 0;
+        for (cart_index, stride) in indices.iter().zip(self.strides.iter()) {
+            index += cart_index * stride;
+        }
+        return index;
+    }
+
+    /**
+    Convert a linear index to a cartesian index which is written into `cart_indices`.
+
+    If the length of `cart_indices` is not equal to the number of dimensions or the linear index is
+    out of bounds, this function returns an error (and does not change `cart_indices`).
+    See also [`lin_to_cart_dyn`](crate::lin_to_cart_dyn).
+    ```
+    use cart_lin::ShapeDyn;
+
+    let shape = ShapeDyn::new(&[2, 3]);
+    let mut indices = [0, 0];
+    assert!(shape.to_cart(4, &mut indices).is_ok());
+    assert_eq!(indices, [1, 1]);
+    assert!(shape.to_cart(6, &mut indices).is_err()); // Out of bounds
+    ```
+     */
+    pub fn to_cart(&self, index: usize, cart_indices: &mut [usize]) -> Result<(), IndexError> {
+        if cart_indices.len() != self.dim_size.len() {
+            return Err(IndexError::DimensionMismatch {
+                expected: self.dim_size.len(),
+                got: cart_indices.len(),
+            });
+        }
+        check_linear(index, self.len)?;
+        self.to_cart_unchecked(index, cart_indices);
+        return Ok(());
+    }
+
+    /**
+    Like [`ShapeDyn::to_cart`], but without the checks.
+     */
+    pub fn to_cart_unchecked(&self, index: usize, cart_indices: &mut [usize]) {
+        O::lin_to_cart_dyn_unchecked(index, &self.dim_size, cart_indices);
+    }
+}
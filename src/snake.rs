@@ -0,0 +1,200 @@
+/*!
+Boustrophedon (snake) traversal of cartesian indices.
+ */
+
+use crate::error::validate_shape;
+use crate::index_int::IndexInt;
+use crate::traversal::{Traversal, Traverse, impl_traversal_iterator};
+
+/**
+Position of a [`SnakeIndices`] iterator within the traversal.
+ */
+#[derive(Debug, Clone)]
+struct Cursor<const N: usize, T: IndexInt> {
+    cart: [T; N],
+    // Row-major linear index of `cart`
+    lin: T,
+    // Whether each axis is currently traversed in ascending order
+    ascending: [bool; N],
+}
+
+/**
+The boustrophedon traversal of an array with the given shape.
+ */
+#[derive(Debug, Clone)]
+struct Snake<const N: usize, T: IndexInt> {
+    dim_size: [T; N],
+    // Row-major strides
+    strides: [T; N],
+}
+
+/**
+An iterator over cartesian indices in boustrophedon (snake) order.
+
+Like [`CartesianIndices`](crate::CartesianIndices), the last axis varies fastest. However, the direction
+of each inner axis is reversed on every other pass, so consecutive cartesian indices differ by exactly one
+step along a single axis (e.g. when moving stage motors or scanning detectors). Each item consists of the
+row-major linear index (see [`cart_to_lin`](crate::cart_to_lin)) of the cartesian index and the cartesian
+index itself:
+```
+use cart_lin::SnakeIndices;
+
+let mut cartiter = SnakeIndices::new([2, 3]);
+assert_eq!(cartiter.len(), 6);
+assert_eq!(cartiter.next(), Some((0, [0, 0])));
+assert_eq!(cartiter.next(), Some((1, [0, 1])));
+assert_eq!(cartiter.next(), Some((2, [0, 2])));
+assert_eq!(cartiter.next(), Some((5, [1, 2])));
+assert_eq!(cartiter.next(), Some((4, [1, 1])));
+assert_eq!(cartiter.next(), Some((3, [1, 0])));
+assert_eq!(cartiter.next(), None);
+```
+In higher dimensions, every axis except the first one changes its direction whenever an outer axis
+advances:
+```
+use cart_lin::SnakeIndices;
+
+let carts: Vec<_> = SnakeIndices::new([2, 2, 2]).map(|(_, cart)| cart).collect();
+assert_eq!(
+    carts,
+    [
+        [0, 0, 0], [0, 0, 1], [0, 1, 1], [0, 1, 0],
+        [1, 1, 0], [1, 1, 1], [1, 0, 1], [1, 0, 0],
+    ]
+);
+```
+The iterator supports random access via [`Iterator::nth`] and traversal from the back. Random access
+computes the cartesian index from the position within the traversal, while [`Iterator::next`] updates the
+cartesian and the linear index incrementally:
+```
+use cart_lin::SnakeIndices;
+
+let mut cartiter = SnakeIndices::new([3, 4]);
+assert_eq!(cartiter.nth(5), Some((6, [1, 2])));
+assert_eq!(cartiter.next(), Some((5, [1, 1])));
+assert_eq!(cartiter.next_back(), Some((11, [2, 3])));
+assert_eq!(cartiter.nth_back(1), Some((9, [2, 1])));
+assert_eq!(cartiter.len(), 2);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SnakeIndices<const N: usize, T: IndexInt = usize> {
+    traversal: Traversal<Snake<N, T>, T>,
+}
+
+impl<const N: usize, T: IndexInt> SnakeIndices<N, T> {
+    /**
+    Creates a new [`SnakeIndices`] iterator using the given dimension sizes.
+    ```
+    use cart_lin::SnakeIndices;
+
+    let mut cartiter = SnakeIndices::new([2, 0]);
+    assert_eq!(cartiter.next(), None);
+
+    let mut cartiter = SnakeIndices::<0>::new([]);
+    assert_eq!(cartiter.next(), Some((0, [])));
+    assert_eq!(cartiter.next(), None);
+    ```
+
+    # Panics
+
+    Panics if a dimension size is negative or if the total number of elements overflows the index type
+    (see [`validate_shape`]).
+     */
+    pub fn new(dim_size: [T; N]) -> Self {
+        let len = match validate_shape(&dim_size) {
+            Ok(len) => len,
+            Err(err) => panic!("{err}"),
+        };
+
+        let mut strides = [T::ONE; N];
+        let mut stride = T::ONE;
+        for (axis_stride, size) in strides.iter_mut().zip(dim_size.iter()).rev() {
+            *axis_stride = stride;
+            stride *= *size;
+        }
+
+        let first = Cursor {
+            cart: [T::ZERO; N],
+            lin: T::ZERO,
+            ascending: [true; N],
+        };
+        return Self {
+            traversal: Traversal::new(Snake { dim_size, strides }, len, first),
+        };
+    }
+}
+
+impl<const N: usize, T: IndexInt> Traverse<T> for Snake<N, T> {
+    type Cursor = Cursor<N, T>;
+    type Item = (T, [T; N]);
+
+    /**
+    An axis is traversed in descending order if the number of completed passes along this axis (the
+    position divided by the number of elements of this axis and all faster axes) is odd.
+     */
+    fn cursor_at(&self, position: T) -> Cursor<N, T> {
+        let two = T::ONE + T::ONE;
+        let mut cursor = Cursor {
+            cart: [T::ZERO; N],
+            lin: T::ZERO,
+            ascending: [true; N],
+        };
+        let mut rest = position;
+        for axis in (0..N).rev() {
+            let size = self.dim_size[axis];
+            let digit = rest % size;
+            rest /= size;
+            cursor.ascending[axis] = rest % two == T::ZERO;
+            cursor.cart[axis] = if cursor.ascending[axis] {
+                digit
+            } else {
+                size - T::ONE - digit
+            };
+            cursor.lin += cursor.cart[axis] * self.strides[axis];
+        }
+        return cursor;
+    }
+
+    fn advance(&self, cursor: &mut Cursor<N, T>) {
+        for axis in (0..N).rev() {
+            if cursor.ascending[axis] {
+                if cursor.cart[axis] + T::ONE < self.dim_size[axis] {
+                    cursor.cart[axis] += T::ONE;
+                    cursor.lin += self.strides[axis];
+                    return;
+                }
+            } else if cursor.cart[axis] > T::ZERO {
+                cursor.cart[axis] = cursor.cart[axis] - T::ONE;
+                cursor.lin = cursor.lin - self.strides[axis];
+                return;
+            }
+            // End of the pass along this axis: an outer axis advances and this axis turns around
+            cursor.ascending[axis] = !cursor.ascending[axis];
+        }
+    }
+
+    fn retreat(&self, cursor: &mut Cursor<N, T>) {
+        for axis in (0..N).rev() {
+            if cursor.ascending[axis] {
+                if cursor.cart[axis] > T::ZERO {
+                    cursor.cart[axis] = cursor.cart[axis] - T::ONE;
+                    cursor.lin = cursor.lin - self.strides[axis];
+                    return;
+                }
+            } else if cursor.cart[axis] + T::ONE < self.dim_size[axis] {
+                cursor.cart[axis] += T::ONE;
+                cursor.lin += self.strides[axis];
+                return;
+            }
+            // Start of the pass along this axis: the previous pass was traversed in the other direction
+            cursor.ascending[axis] = !cursor.ascending[axis];
+        }
+    }
+
+    fn item(&self, cursor: &Cursor<N, T>) -> (T, [T; N]) {
+        return (cursor.lin, cursor.cart);
+    }
+}
+
+impl_traversal_iterator!([const N: usize, T: IndexInt] SnakeIndices<N, T>, T, (T, [T; N]));
@@ -0,0 +1,277 @@
+/*!
+Memory layouts described by a base offset and explicit (possibly negative) strides.
+ */
+
+use crate::error::{IndexError, check_indices};
+use crate::order::Order;
+
+/**
+A memory layout described by a base offset and a signed stride for each axis.
+
+In contrast to [`RowMajor`](crate::RowMajor) and [`ColumnMajor`](crate::ColumnMajor), whose strides are
+implicitly given by the dimension sizes, a [`StridedLayout`] can describe sliced, transposed or reversed
+views into a buffer (such as the views handed out by [ndarray](https://crates.io/crates/ndarray)). The
+offset of the element at the cartesian index `[i_0, i_1, ...]` is
+`offset + i_0 * strides[0] + i_1 * strides[1] + ...`.
+```
+use cart_lin::StridedLayout;
+
+// Buffer of a 3 x 4 matrix in row-major order:
+// [ 0  1  2  3]
+// [ 4  5  6  7]
+// [ 8  9 10 11]
+let buffer: Vec<usize> = (0..12).collect();
+
+// View of the matrix with reversed rows and every second column:
+// [ 8 10]
+// [ 4  6]
+// [ 0  2]
+let view = StridedLayout::new(8, [3, 2], [-4, 2]);
+assert_eq!(buffer[view.cart_to_offset(&[0, 0]).unwrap()], 8);
+assert_eq!(buffer[view.cart_to_offset(&[0, 1]).unwrap()], 10);
+assert_eq!(buffer[view.cart_to_offset(&[2, 1]).unwrap()], 2);
+
+// Reverse mapping
+assert_eq!(view.offset_to_cart(6).unwrap(), [1, 1]);
+assert!(view.offset_to_cart(5).is_err()); // Element 5 is not part of the view
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StridedLayout<const N: usize> {
+    offset: usize,
+    dim_size: [usize; N],
+    strides: [isize; N],
+}
+
+impl<const N: usize> StridedLayout<N> {
+    /**
+    Creates a new [`StridedLayout`] from the base offset (the offset of the element with the cartesian
+    index `[0, 0, ...]`), the size of each dimension and the stride of each axis.
+    ```
+    use cart_lin::StridedLayout;
+
+    // 2 x 3 matrix in column-major order
+    let layout = StridedLayout::new(0, [2, 3], [1, 2]);
+    assert_eq!(layout.cart_to_offset(&[1, 2]).unwrap(), 5);
+    ```
+     */
+    pub fn new(offset: usize, dim_size: [usize; N], strides: [isize; N]) -> Self {
+        return Self {
+            offset,
+            dim_size,
+            strides,
+        };
+    }
+
+    /**
+    Creates the [`StridedLayout`] of a contiguous buffer in the memory order `O` (with base offset zero).
+    ```
+    use cart_lin::{ColumnMajor, RowMajor, StridedLayout};
+
+    assert_eq!(StridedLayout::from_order::<RowMajor>([2, 3, 4]).strides(), &[12, 4, 1]);
+    assert_eq!(StridedLayout::from_order::<ColumnMajor>([2, 3, 4]).strides(), &[1, 2, 6]);
+    ```
+     */
+    pub fn from_order<O: Order>(dim_size: [usize; N]) -> Self {
+        let mut strides = [0; N];
+        O::strides(&dim_size, &mut strides);
+        return Self::new(0, dim_size, strides.map(|stride| stride as isize));
+    }
+
+    /**
+    Returns the base offset (the offset of the element with the cartesian index `[0, 0, ...]`).
+     */
+    pub fn offset(&self) -> usize {
+        return self.offset;
+    }
+
+    /**
+    Returns the size of each dimension.
+     */
+    pub fn dim_size(&self) -> &[usize; N] {
+        return &self.dim_size;
+    }
+
+    /**
+    Returns the stride of each axis.
+     */
+    pub fn strides(&self) -> &[isize; N] {
+        return &self.strides;
+    }
+
+    /**
+    Convert a cartesian index into an offset within the underlying buffer.
+
+    If any of the cartesian indices are out of bounds or if the resulting offset is negative or
+    does not fit into an `usize`, this function returns an error.
+    ```
+    use cart_lin::{IndexError, StridedLayout};
+
+    // Reversed vector with three elements
+    let layout = StridedLayout::new(2, [3], [-1]);
+    assert_eq!(layout.cart_to_offset(&[0]).unwrap(), 2);
+    assert_eq!(layout.cart_to_offset(&[2]).unwrap(), 0);
+    assert!(layout.cart_to_offset(&[3]).is_err()); // Out of bounds
+
+    // The element at [1] would lie before the start of the buffer
+    let layout = StridedLayout::new(0, [3], [-1]);
+    assert_eq!(layout.cart_to_offset(&[1]), Err(IndexError::NegativeOffset));
+
+    // The element at [2] would lie beyond usize::MAX
+    let layout = StridedLayout::new(usize::MAX - 1, [3], [1]);
+    assert_eq!(layout.cart_to_offset(&[2]), Err(IndexError::Overflow));
+    ```
+     */
+    pub fn cart_to_offset(&self, indices: &[usize; N]) -> Result<usize, IndexError> {
+        check_indices(indices, &self.dim_size)?;
+        let mut offset = self.offset as i128;
+        for (index, stride) in indices.iter().zip(self.strides) {
+            // The product always fits into an i128, but the sum might overflow for many axes
+            offset = offset
+                .checked_add(*index as i128 * stride as i128)
+                .ok_or(IndexError::Overflow)?;
+        }
+        if offset < 0 {
+            return Err(IndexError::NegativeOffset);
+        }
+        return usize::try_from(offset).map_err(|_| IndexError::Overflow);
+    }
+
+    /**
+    Like [`StridedLayout::cart_to_offset`], but without the checks.
+
+    Despite the name, this function itself is safe. However, the offset received from this function might be invalid.
+    Using such an invalid offset may cause an out-of-bounds read.
+    ```
+    use cart_lin::StridedLayout;
+
+    let layout = StridedLayout::new(2, [3], [-1]);
+    assert_eq!(layout.cart_to_offset_unchecked(&[2]), 0);
+    assert_eq!(layout.cart_to_offset_unchecked(&[3]), usize::MAX); // Nonsensical value (wrapping around)
+    ```
+     */
+    pub fn cart_to_offset_unchecked(&self, indices: &[usize; N]) -> usize {
+        let mut offset = self.offset;
+        for (index, stride) in indices.iter().zip(self.strides) {
+            offset = offset.wrapping_add_signed((*index as isize).wrapping_mul(stride));
+        }
+        return offset;
+    }
+
+    /**
+    Convert an offset within the underlying buffer back into a cartesian index.
+
+    If no element of the layout is located at `offset`, this function returns an error. The reverse mapping
+    is unique if the layout is non-overlapping (i.e. no two cartesian indices map onto the same offset),
+    which is the case for all views created by slicing, transposing or reversing the axes of a contiguous
+    buffer. For overlapping layouts (e.g. with a stride of zero for broadcasting), one of the matching
+    cartesian indices is returned.
+    ```
+    use cart_lin::StridedLayout;
+
+    use cart_lin::IndexError;
+
+    // Transposed 2 x 3 matrix (column-major strides)
+    let layout = StridedLayout::new(0, [2, 3], [1, 2]);
+    assert_eq!(layout.offset_to_cart(0).unwrap(), [0, 0]);
+    assert_eq!(layout.offset_to_cart(1).unwrap(), [1, 0]);
+    assert_eq!(layout.offset_to_cart(2).unwrap(), [0, 1]);
+    assert_eq!(layout.offset_to_cart(6), Err(IndexError::UnmappedOffset { offset: 6 }));
+    ```
+     */
+    pub fn offset_to_cart(&self, offset: usize) -> Result<[usize; N], IndexError> {
+        if self.dim_size.contains(&0) {
+            return Err(IndexError::UnmappedOffset { offset });
+        }
+
+        // Process the axes from the largest to the smallest absolute stride
+        let mut axes = [0; N];
+        for (i, axis) in axes.iter_mut().enumerate() {
+            *axis = i;
+        }
+        axes.sort_unstable_by_key(|axis| core::cmp::Reverse(self.strides[*axis].unsigned_abs()));
+
+        // Range of the offsets which can be reached by the axes axes[k..] (relative to the base offset)
+        let mut reach = [[0i128; 2]; N];
+        let mut lower = 0;
+        let mut upper = 0;
+        for (k, axis) in axes.iter().enumerate().rev() {
+            let extent = (self.dim_size[*axis] - 1) as i128 * self.strides[*axis] as i128;
+            lower += extent.min(0);
+            upper += extent.max(0);
+            reach[k] = [lower, upper];
+        }
+
+        let mut indices = [0; N];
+        let remainder = offset as i128 - self.offset as i128;
+        if self.solve(&axes, &reach, 0, remainder, &mut indices) {
+            return Ok(indices);
+        } else {
+            return Err(IndexError::UnmappedOffset { offset });
+        }
+    }
+
+    /**
+    Depth-first search for the cartesian index of `remainder` over the axes `axes[k..]`.
+
+    For each axis, only the indices are tried for which the remaining offset can still be reached by the
+    following axes. For non-overlapping layouts created from contiguous buffers, this leaves at most one
+    candidate per axis.
+     */
+    fn solve(
+        &self,
+        axes: &[usize; N],
+        reach: &[[i128; 2]; N],
+        k: usize,
+        remainder: i128,
+        indices: &mut [usize; N],
+    ) -> bool {
+        if k == N {
+            return remainder == 0;
+        }
+        if remainder < reach[k][0] || remainder > reach[k][1] {
+            return false;
+        }
+
+        let axis = axes[k];
+        let stride = self.strides[axis] as i128;
+        let last = (self.dim_size[axis] - 1) as i128;
+        let [rest_lower, rest_upper] = if k + 1 < N { reach[k + 1] } else { [0, 0] };
+
+        // Candidates fulfill rest_lower <= remainder - index * stride <= rest_upper
+        let (lowest, highest) = if stride == 0 {
+            (0, last)
+        } else if stride > 0 {
+            (
+                div_ceil(remainder - rest_upper, stride),
+                div_floor(remainder - rest_lower, stride),
+            )
+        } else {
+            (
+                div_ceil(remainder - rest_lower, stride),
+                div_floor(remainder - rest_upper, stride),
+            )
+        };
+
+        for index in lowest.max(0)..=highest.min(last) {
+            indices[axis] = index as usize;
+            if self.solve(axes, reach, k + 1, remainder - index * stride, indices) {
+                return true;
+            }
+        }
+        return false;
+    }
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if (a % b != 0) && ((a < 0) != (b < 0)) {
+        return q - 1;
+    } else {
+        return q;
+    }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    return -div_floor(-a, b);
+}
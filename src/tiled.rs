@@ -0,0 +1,343 @@
+/*!
+Tiled (blocked) iteration over cartesian indices, e.g. for cache blocking.
+ */
+
+use core::iter::FusedIterator;
+
+use crate::cartesian_indices::CartesianIndices;
+use crate::error::{IndexError, validate_shape};
+use crate::index_int::IndexInt;
+
+/**
+An iterator over the tiles of a box of cartesian indices.
+
+The box is divided into tiles of the given tile shape, starting at the lower bound of each axis. If the
+extent of an axis is not divisible by the tile size, the last tile along this axis is clipped to the upper
+bound. The iterator yields the bounding box of each tile (lower and upper bound of each axis, in the format
+accepted by [`CartesianIndices::from_bounds`]), with the tiles traversed in row-major order:
+```
+use cart_lin::TiledIndices;
+
+// 5 x 3 matrix, divided into 2 x 2 tiles
+let mut tiles = TiledIndices::new([5, 3], [2, 2]).unwrap();
+assert_eq!(tiles.len(), 6);
+assert_eq!(tiles.next(), Some([[0, 2], [0, 2]]));
+assert_eq!(tiles.next(), Some([[0, 2], [2, 3]]));
+assert_eq!(tiles.next(), Some([[2, 4], [0, 2]]));
+assert_eq!(tiles.next(), Some([[2, 4], [2, 3]]));
+assert_eq!(tiles.next(), Some([[4, 5], [0, 2]]));
+assert_eq!(tiles.next(), Some([[4, 5], [2, 3]]));
+assert_eq!(tiles.next(), None);
+```
+The outer loop over the tiles and the inner loop over the elements of each tile can be written as:
+```
+use cart_lin::{CartesianIndices, TiledIndices};
+
+for tile in TiledIndices::new([100, 100], [16, 16]).unwrap() {
+    for [i, j] in CartesianIndices::from_bounds(tile).unwrap() {
+        // Process element [i, j]
+    }
+}
+```
+Alternatively, [`TiledIndices::elements`] returns a single iterator over the elements in tile-major order.
+ */
+#[derive(Debug, Clone)]
+pub struct TiledIndices<const N: usize, T: IndexInt = usize> {
+    // Lower bounds of the tiles
+    origins: CartesianIndices<N, T>,
+    // Upper bounds of the whole box
+    ends: [T; N],
+    tile_shape: [T; N],
+}
+
+/**
+Checks whether each tile size is positive.
+ */
+fn check_tile_shape<T: IndexInt>(tile_shape: &[T]) -> Result<(), IndexError<T>> {
+    for (axis, size) in tile_shape.iter().enumerate() {
+        if *size <= T::ZERO {
+            return Err(IndexError::InvalidStep { axis, step: *size });
+        }
+    }
+    return Ok(());
+}
+
+impl<const N: usize, T: IndexInt> TiledIndices<N, T> {
+    /**
+    Creates a new [`TiledIndices`] iterator over an array with the given dimension sizes.
+
+    If `dim_size` is not a valid shape (see [`validate_shape`]), an [`IndexError`] is returned. If a tile
+    size is not positive, [`IndexError::InvalidStep`] is returned (the tile size is the step between the
+    lower bounds of two consecutive tiles):
+    ```
+    use cart_lin::{IndexError, TiledIndices};
+
+    // Dimensions of size zero result in an empty iterator
+    assert_eq!(TiledIndices::new([4, 0], [2, 2]).unwrap().count(), 0);
+
+    assert_eq!(
+        TiledIndices::new([4, 4], [2, 0]).unwrap_err(),
+        IndexError::InvalidStep { axis: 1, step: 0 }
+    );
+    ```
+     */
+    pub fn new(dim_size: [T; N], tile_shape: [T; N]) -> Result<Self, IndexError<T>> {
+        validate_shape(&dim_size)?;
+        check_tile_shape(&tile_shape)?;
+
+        // An axis of size zero does not contain any tiles
+        let mut bounds = [[T::ZERO, T::ZERO, T::ONE]; N];
+        for (limits, (dim, size)) in bounds.iter_mut().zip(dim_size.iter().zip(tile_shape)) {
+            *limits = [T::ZERO, *dim, size];
+        }
+        return Ok(Self {
+            origins: CartesianIndices::from_bounds_with_step_unchecked(bounds),
+            ends: dim_size,
+            tile_shape,
+        });
+    }
+
+    /**
+    Creates a new [`TiledIndices`] iterator over the box with the given lower and upper bounds. See
+    [`CartesianIndices::from_bounds`] for the requirements on the bounds. The first tile of each axis
+    starts at its lower bound:
+    ```
+    use cart_lin::TiledIndices;
+
+    let tiles: Vec<_> = TiledIndices::from_bounds([[1, 8]], [3]).unwrap().collect();
+    assert_eq!(tiles, [[[1, 4]], [[4, 7]], [[7, 8]]]);
+    ```
+     */
+    pub fn from_bounds(bounds: [[T; 2]; N], tile_shape: [T; N]) -> Result<Self, IndexError<T>> {
+        // Validates the bounds and the total number of elements
+        CartesianIndices::from_bounds(bounds)?;
+        check_tile_shape(&tile_shape)?;
+
+        let mut bounds_with_step = [[T::ZERO, T::ZERO, T::ONE]; N];
+        for (limits, ([start, end], size)) in bounds_with_step
+            .iter_mut()
+            .zip(bounds.iter().zip(tile_shape))
+        {
+            *limits = [*start, *end, size];
+        }
+        return Ok(Self {
+            origins: CartesianIndices::from_bounds_with_step_unchecked(bounds_with_step),
+            ends: bounds.map(|limits| limits[1]),
+            tile_shape,
+        });
+    }
+
+    /**
+    Returns an iterator over the cartesian indices of all remaining tiles in tile-major order, i.e. all
+    elements of a tile are yielded before the elements of the next tile. Within each tile, the elements
+    are traversed in row-major order. Creating the iterator visits each remaining tile once in order to
+    compute the exact number of elements.
+    ```
+    use cart_lin::TiledIndices;
+
+    let elements: Vec<_> = TiledIndices::new([2, 3], [2, 2]).unwrap().elements().collect();
+    assert_eq!(elements, [[0, 0], [0, 1], [1, 0], [1, 1], [0, 2], [1, 2]]);
+    ```
+     */
+    pub fn elements(self) -> TiledElements<N, T> {
+        let mut remaining = T::ZERO;
+        for tile in self.clone() {
+            remaining += tile_len(&tile);
+        }
+        return TiledElements {
+            tiles: self,
+            front: None,
+            back: None,
+            remaining,
+        };
+    }
+
+    /**
+    Returns the bounding box of the tile with the given lower bounds, clipped to the upper bounds.
+     */
+    fn tile_at(&self, origin: [T; N]) -> [[T; 2]; N] {
+        let mut tile = [[T::ZERO, T::ZERO]; N];
+        for (limits, (start, (end, size))) in tile.iter_mut().zip(
+            origin
+                .into_iter()
+                .zip(self.ends.iter().zip(self.tile_shape.iter())),
+        ) {
+            // Comparing the extents avoids overflows at the end of the index type
+            if *end - start <= *size {
+                *limits = [start, *end];
+            } else {
+                *limits = [start, start + *size];
+            }
+        }
+        return tile;
+    }
+}
+
+/**
+Returns the number of elements of a tile.
+ */
+fn tile_len<T: IndexInt>(tile: &[[T; 2]]) -> T {
+    let mut len = T::ONE;
+    for limits in tile.iter() {
+        len *= limits[1] - limits[0];
+    }
+    return len;
+}
+
+impl<const N: usize, T: IndexInt> Iterator for TiledIndices<N, T> {
+    type Item = [[T; 2]; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let origin = self.origins.next()?;
+        return Some(self.tile_at(origin));
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let origin = self.origins.nth(n)?;
+        return Some(self.tile_at(origin));
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.origins.size_hint();
+    }
+}
+
+impl<const N: usize, T: IndexInt> DoubleEndedIterator for TiledIndices<N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let origin = self.origins.next_back()?;
+        return Some(self.tile_at(origin));
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let origin = self.origins.nth_back(n)?;
+        return Some(self.tile_at(origin));
+    }
+}
+
+impl<const N: usize, T: IndexInt> ExactSizeIterator for TiledIndices<N, T> where
+    CartesianIndices<N, T>: ExactSizeIterator
+{
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for TiledIndices<N, T> {}
+
+/**
+An iterator over the cartesian indices of a [`TiledIndices`] in tile-major order.
+
+This struct is created by [`TiledIndices::elements`], see its documentation for more. It supports
+traversal from the back, and [`Iterator::nth`] skips whole tiles without visiting their elements:
+```
+use cart_lin::TiledIndices;
+
+let mut elements = TiledIndices::new([4, 4], [2, 2]).unwrap().elements();
+assert_eq!(elements.len(), 16);
+assert_eq!(elements.nth(9), Some([2, 1]));
+assert_eq!(elements.next_back(), Some([3, 3]));
+assert_eq!(elements.len(), 5);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct TiledElements<const N: usize, T: IndexInt = usize> {
+    tiles: TiledIndices<N, T>,
+    // Elements of the tile which is currently traversed from the front
+    front: Option<CartesianIndices<N, T>>,
+    // Elements of the tile which is currently traversed from the back
+    back: Option<CartesianIndices<N, T>>,
+    // Total number of remaining elements
+    remaining: T,
+}
+
+impl<const N: usize, T: IndexInt> Iterator for TiledElements<N, T> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.nth(0);
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // If n does not fit into T, it is larger than the number of remaining elements anyway
+        let mut n = match T::try_from(n) {
+            Ok(n) if n < self.remaining => n,
+            _ => {
+                self.remaining = T::ZERO;
+                self.tiles.origins.nth(usize::MAX);
+                self.front = None;
+                self.back = None;
+                return None;
+            }
+        };
+        self.remaining = self.remaining - n - T::ONE;
+
+        loop {
+            if let Some(front) = &mut self.front {
+                let len = front.remaining();
+                if n < len {
+                    return front.nth(n.try_into().ok()?);
+                }
+                n = n - len;
+                self.front = None;
+            }
+            self.front = match self.tiles.next() {
+                Some(tile) => Some(CartesianIndices::from_bounds_unchecked(tile)),
+                // The remaining elements belong to the tile traversed from the back
+                None => {
+                    let back = self.back.as_mut()?;
+                    return back.nth(n.try_into().ok()?);
+                }
+            };
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining.try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+}
+
+impl<const N: usize, T: IndexInt> DoubleEndedIterator for TiledElements<N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.nth_back(0);
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let mut n = match T::try_from(n) {
+            Ok(n) if n < self.remaining => n,
+            _ => {
+                self.remaining = T::ZERO;
+                self.tiles.origins.nth(usize::MAX);
+                self.front = None;
+                self.back = None;
+                return None;
+            }
+        };
+        self.remaining = self.remaining - n - T::ONE;
+
+        loop {
+            if let Some(back) = &mut self.back {
+                let len = back.remaining();
+                if n < len {
+                    return back.nth_back(n.try_into().ok()?);
+                }
+                n = n - len;
+                self.back = None;
+            }
+            self.back = match self.tiles.next_back() {
+                Some(tile) => Some(CartesianIndices::from_bounds_unchecked(tile)),
+                // The remaining elements belong to the tile traversed from the front
+                None => {
+                    let front = self.front.as_mut()?;
+                    return front.nth_back(n.try_into().ok()?);
+                }
+            };
+        }
+    }
+}
+
+impl<const N: usize, T: IndexInt> ExactSizeIterator for TiledElements<N, T> where
+    CartesianIndices<N, T>: ExactSizeIterator
+{
+}
+
+impl<const N: usize, T: IndexInt> FusedIterator for TiledElements<N, T> {}
@@ -0,0 +1,194 @@
+/*!
+Double-ended random access iterators over a sequence of known length, for layouts which can both step to
+the neighbouring element and jump to the element at an arbitrary position.
+ */
+
+use core::fmt::Debug;
+
+use crate::index_int::IndexInt;
+
+/**
+A sequence of elements which can be traversed from both ends, e.g. the stored elements of a layout in
+memory order.
+ */
+pub(crate) trait Traverse<T: IndexInt> {
+    /// State from which an item is computed and which can be moved to the neighbouring elements.
+    type Cursor: Debug + Clone;
+    type Item;
+
+    /**
+    Returns the cursor of the element at the given position, which is smaller than the number of elements.
+     */
+    fn cursor_at(&self, position: T) -> Self::Cursor;
+
+    /**
+    Moves the cursor to the next element. The cursor does not point to the last element.
+     */
+    fn advance(&self, cursor: &mut Self::Cursor);
+
+    /**
+    Moves the cursor to the previous element. The cursor does not point to the first element.
+     */
+    fn retreat(&self, cursor: &mut Self::Cursor);
+
+    /**
+    Returns the item of the element the cursor points to.
+     */
+    fn item(&self, cursor: &Self::Cursor) -> Self::Item;
+}
+
+/**
+Shared state of the iterators over a [`Traverse`] sequence. The public iterators wrap it and forward to
+it via [`impl_traversal_iterator`].
+
+Both ends are updated incrementally by [`Iterator::next`] and [`DoubleEndedIterator::next_back`], while
+[`Iterator::nth`] and [`DoubleEndedIterator::nth_back`] recompute the cursor from the position.
+ */
+#[derive(Debug, Clone)]
+pub(crate) struct Traversal<L: Traverse<T>, T: IndexInt> {
+    layout: L,
+    // Position of the next element from the front
+    front_position: T,
+    // Position after the next element from the back
+    back_position: T,
+    // Next element from the front
+    front: L::Cursor,
+    // Next element from the back
+    back: L::Cursor,
+}
+
+impl<L: Traverse<T>, T: IndexInt> Traversal<L, T> {
+    /**
+    Creates a traversal over `len` elements. The cursor `first` points to the first element, or is an
+    arbitrary placeholder if `len` is zero.
+     */
+    pub(crate) fn new(layout: L, len: T, first: L::Cursor) -> Self {
+        let back = if len > T::ZERO {
+            layout.cursor_at(len - T::ONE)
+        } else {
+            first.clone()
+        };
+        return Self {
+            layout,
+            front_position: T::ZERO,
+            back_position: len,
+            front: first,
+            back,
+        };
+    }
+
+    /**
+    Returns the number of remaining elements.
+     */
+    fn remaining(&self) -> T {
+        return self.back_position - self.front_position;
+    }
+
+    pub(crate) fn next(&mut self) -> Option<L::Item> {
+        if self.front_position >= self.back_position {
+            return None;
+        }
+        let item = self.layout.item(&self.front);
+        self.front_position += T::ONE;
+        if self.front_position < self.back_position {
+            self.layout.advance(&mut self.front);
+        }
+        return Some(item);
+    }
+
+    pub(crate) fn nth(&mut self, n: usize) -> Option<L::Item> {
+        // If n does not fit into T, it is larger than the number of remaining elements anyway
+        match T::try_from(n) {
+            Ok(n) if n < self.remaining() => {
+                if n > T::ZERO {
+                    self.front_position += n;
+                    self.front = self.layout.cursor_at(self.front_position);
+                }
+                return self.next();
+            }
+            _ => {
+                self.front_position = self.back_position;
+                return None;
+            }
+        }
+    }
+
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining().try_into() {
+            Ok(remaining) => return (remaining, Some(remaining)),
+            Err(_) => return (usize::MAX, None),
+        }
+    }
+
+    pub(crate) fn next_back(&mut self) -> Option<L::Item> {
+        if self.front_position >= self.back_position {
+            return None;
+        }
+        let item = self.layout.item(&self.back);
+        self.back_position = self.back_position - T::ONE;
+        if self.front_position < self.back_position {
+            self.layout.retreat(&mut self.back);
+        }
+        return Some(item);
+    }
+
+    pub(crate) fn nth_back(&mut self, n: usize) -> Option<L::Item> {
+        match T::try_from(n) {
+            Ok(n) if n < self.remaining() => {
+                if n > T::ZERO {
+                    self.back_position = self.back_position - n;
+                    self.back = self.layout.cursor_at(self.back_position - T::ONE);
+                }
+                return self.next_back();
+            }
+            _ => {
+                self.back_position = self.front_position;
+                return None;
+            }
+        }
+    }
+}
+
+/*
+Implements Iterator, DoubleEndedIterator, ExactSizeIterator and FusedIterator for a public iterator
+whose field `traversal` is a Traversal with the index type $t. Like for CartesianIndices,
+ExactSizeIterator is only implemented for index types whose number of elements fits into an usize.
+ */
+macro_rules! impl_traversal_iterator {
+    ([$($generics:tt)*] $iter:ty, $t:ident, $item:ty) => {
+        impl<$($generics)*> Iterator for $iter {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                return self.traversal.next();
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                return self.traversal.nth(n);
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                return self.traversal.size_hint();
+            }
+        }
+
+        impl<$($generics)*> DoubleEndedIterator for $iter {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                return self.traversal.next_back();
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                return self.traversal.nth_back(n);
+            }
+        }
+
+        impl<$($generics)*> ExactSizeIterator for $iter where
+            $crate::CartesianIndices<1, $t>: ExactSizeIterator
+        {
+        }
+
+        impl<$($generics)*> core::iter::FusedIterator for $iter {}
+    };
+}
+
+pub(crate) use impl_traversal_iterator;
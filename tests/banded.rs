@@ -0,0 +1,183 @@
+use cart_lin::{BandedLayout, IndexError};
+
+/// Reference implementation: enumerates the elements within the band column by column, together with
+/// their index in the band storage as documented by LAPACK (with 0-based indices):
+/// AB(ku + i - j, j) = A(i, j) for max(0, j - ku) <= i <= min(m - 1, j + kl)
+fn reference_items(rows: usize, columns: usize, kl: usize, ku: usize) -> Vec<(usize, [usize; 2])> {
+    let ldab = kl + ku + 1;
+    let mut res = Vec::new();
+    for j in 0..columns {
+        for i in 0..rows {
+            if i + ku >= j && i <= j + kl {
+                res.push((ku + i - j + j * ldab, [i, j]));
+            }
+        }
+    }
+    res
+}
+
+/// Rectangular matrices with empty columns and rows, and bands wider than the matrix.
+const SHAPES: [(usize, usize, usize, usize); 5] = [
+    (3, 7, 1, 2),
+    (7, 3, 2, 0),
+    (5, 5, 0, 0),
+    (2, 2, 4, 5),
+    (3, 2, 1, 6),
+];
+
+#[test]
+fn test_matches_lapack() {
+    for (rows, columns, kl, ku) in SHAPES {
+        let layout = BandedLayout::new(rows, columns, kl, ku).unwrap();
+        let items = reference_items(rows, columns, kl, ku);
+        assert_eq!(layout.len(), (kl + ku + 1) * columns);
+        for (lin, cart) in items.iter() {
+            assert_eq!(layout.cart_to_lin(cart), Ok(*lin));
+            assert_eq!(layout.cart_to_lin_unchecked(cart), *lin);
+            assert_eq!(layout.lin_to_cart(*lin), Ok(*cart));
+            assert_eq!(layout.lin_to_cart_unchecked(*lin), *cart);
+        }
+
+        // The iterator skips the unused entries at the start and end of each column
+        let reference: Vec<_> = items.iter().map(|(_, cart)| *cart).collect();
+        assert_eq!(layout.indices().collect::<Vec<_>>(), reference);
+        assert!(layout.indices().rev().eq(reference.iter().copied().rev()));
+        for k in 0..=reference.len() {
+            assert_eq!(layout.indices().nth(k), reference.get(k).copied());
+            assert_eq!(
+                layout.indices().nth_back(k),
+                reference.iter().rev().nth(k).copied()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_band_edges() {
+    // The first and last element of each column are mapped, while the neighbouring entries of the band
+    // storage are not
+    for (rows, columns, kl, ku) in SHAPES {
+        let layout = BandedLayout::new(rows, columns, kl, ku).unwrap();
+        let ldab = layout.leading_dimension();
+        for j in 0..columns {
+            let first = j.saturating_sub(ku);
+            let last = (j + kl).min(rows - 1);
+            let column_start = j * ldab;
+            if first > last {
+                // Column without any element within the band
+                for offset in column_start..column_start + ldab {
+                    assert_eq!(
+                        layout.lin_to_cart(offset),
+                        Err(IndexError::UnmappedOffset { offset })
+                    );
+                }
+                continue;
+            }
+            let first_offset = column_start + ku + first - j;
+            let last_offset = column_start + ku + last - j;
+            assert_eq!(layout.lin_to_cart(first_offset), Ok([first, j]));
+            assert_eq!(layout.lin_to_cart(last_offset), Ok([last, j]));
+            if first_offset > column_start {
+                assert_eq!(
+                    layout.lin_to_cart(first_offset - 1),
+                    Err(IndexError::UnmappedOffset {
+                        offset: first_offset - 1
+                    })
+                );
+            }
+            if last_offset + 1 < column_start + ldab {
+                assert_eq!(
+                    layout.lin_to_cart(last_offset + 1),
+                    Err(IndexError::UnmappedOffset {
+                        offset: last_offset + 1
+                    })
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_no_rows() {
+    // Without rows, the band storage is not empty, but contains no elements of the matrix
+    let layout = BandedLayout::new(0usize, 4, 2, 2).unwrap();
+    assert_eq!(layout.len(), 20);
+    assert!(!layout.is_empty());
+    for offset in 0..20 {
+        assert_eq!(
+            layout.lin_to_cart(offset),
+            Err(IndexError::UnmappedOffset { offset })
+        );
+    }
+    assert_eq!(
+        layout.cart_to_lin(&[0, 0]),
+        Err(IndexError::OutOfBounds {
+            axis: 0,
+            index: 0,
+            bound: 0
+        })
+    );
+    assert_eq!(layout.indices().len(), 0);
+    assert_eq!(layout.indices().next_back(), None);
+}
+
+#[test]
+fn test_wide_bands() {
+    // Bands wider than the matrix store every element, but still reserve all diagonals in each column
+    let layout = BandedLayout::new(3usize, 2, 5, 4).unwrap();
+    assert_eq!(layout.leading_dimension(), 10);
+    assert_eq!(layout.len(), 20);
+    assert_eq!(
+        layout.indices().collect::<Vec<_>>(),
+        [[0, 0], [1, 0], [2, 0], [0, 1], [1, 1], [2, 1]]
+    );
+    assert_eq!(layout.cart_to_lin(&[0, 0]), Ok(4));
+    assert_eq!(layout.cart_to_lin(&[2, 0]), Ok(6));
+    assert_eq!(layout.cart_to_lin(&[0, 1]), Ok(13));
+    assert_eq!(layout.cart_to_lin(&[2, 1]), Ok(15));
+    for offset in (0..4).chain(7..13).chain(16..20) {
+        assert_eq!(
+            layout.lin_to_cart(offset),
+            Err(IndexError::UnmappedOffset { offset })
+        );
+    }
+}
+
+#[test]
+fn test_errors() {
+    let layout = BandedLayout::new(4usize, 5, 1, 0).unwrap();
+    assert_eq!(
+        layout.cart_to_lin(&[0, 1]),
+        Err(IndexError::NotStored { row: 0, column: 1 })
+    );
+    assert_eq!(
+        layout.cart_to_lin(&[3, 1]),
+        Err(IndexError::NotStored { row: 3, column: 1 })
+    );
+    assert_eq!(
+        layout.cart_to_lin(&[1, 5]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 5,
+            bound: 5
+        })
+    );
+    assert_eq!(
+        layout.lin_to_cart(10),
+        Err(IndexError::LinearOutOfRange { index: 10, len: 10 })
+    );
+    assert_eq!(
+        BandedLayout::<i32>::new(3, -2, 0, 0),
+        Err(IndexError::NegativeDimension { axis: 1, size: -2 })
+    );
+    assert_eq!(
+        BandedLayout::<i32>::new(3, 3, 0, -1),
+        Err(IndexError::NegativeDimension { axis: 1, size: -1 })
+    );
+    assert_eq!(
+        BandedLayout::new(3, 3, usize::MAX, 0),
+        Err(IndexError::Overflow)
+    );
+
+    assert!(BandedLayout::new(0usize, 0, 2, 2).unwrap().is_empty());
+}
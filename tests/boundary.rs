@@ -0,0 +1,213 @@
+use cart_lin::{Boundary, IndexError, cart_to_lin, cart_to_lin_boundary};
+use proptest::prelude::*;
+
+/// Reference implementation for a single axis. Returns `Err(())` for unresolvable indices and `Ok(None)`
+/// for padding values. Uses `i128` to avoid any overflow.
+fn reference_resolve(index: i128, size: i128, mode: Boundary) -> Result<Option<i128>, ()> {
+    if (0..size).contains(&index) {
+        return Ok(Some(index));
+    }
+    match mode {
+        Boundary::Constant => Ok(None),
+        _ if size == 0 => Err(()),
+        Boundary::Error => Err(()),
+        Boundary::Wrap => Ok(Some(index.rem_euclid(size))),
+        Boundary::Clamp => Ok(Some(index.clamp(0, size - 1))),
+        Boundary::Reflect if size == 1 => Ok(Some(0)),
+        Boundary::Reflect => {
+            // Mirroring at 0 and size - 1 is periodic with period 2 * (size - 1)
+            let period = 2 * (size - 1);
+            let rest = index.rem_euclid(period);
+            Ok(Some(if rest < size { rest } else { period - rest }))
+        }
+    }
+}
+
+fn mode_strategy() -> impl Strategy<Value = Boundary> {
+    prop_oneof![
+        Just(Boundary::Error),
+        Just(Boundary::Wrap),
+        Just(Boundary::Clamp),
+        Just(Boundary::Reflect),
+        Just(Boundary::Constant),
+    ]
+}
+
+#[test]
+fn test_documented_table() {
+    let expected = [
+        (Boundary::Wrap, [1, 2, 3, 0, 1, 2, 3, 0, 1, 2]),
+        (Boundary::Clamp, [0, 0, 0, 0, 1, 2, 3, 3, 3, 3]),
+        (Boundary::Reflect, [3, 2, 1, 0, 1, 2, 3, 2, 1, 0]),
+    ];
+    for (mode, resolved) in expected {
+        for (index, resolved) in (-3..7).zip(resolved) {
+            assert_eq!(
+                cart_to_lin_boundary(&[index], &[4], &[mode]),
+                Ok(Some(resolved))
+            );
+        }
+    }
+    assert_eq!(Boundary::default(), Boundary::Error);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        cart_to_lin_boundary(&[0, -1], &[2, 3], &[Boundary::Wrap, Boundary::Error]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: -1,
+            bound: 3
+        })
+    );
+
+    // Out-of-bounds indices are reported even if another axis contains a padding value
+    assert_eq!(
+        cart_to_lin_boundary(&[-1, 3], &[2, 3], &[Boundary::Constant, Boundary::Error]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 3,
+            bound: 3
+        })
+    );
+
+    // Axes of size zero only support padding
+    for mode in [Boundary::Wrap, Boundary::Clamp, Boundary::Reflect] {
+        assert_eq!(
+            cart_to_lin_boundary(&[1, 0], &[2, 0], &[Boundary::Error, mode]),
+            Err(IndexError::OutOfBounds {
+                axis: 1,
+                index: 0,
+                bound: 0
+            })
+        );
+    }
+    for index in [-1, 0, 1] {
+        assert_eq!(
+            cart_to_lin_boundary(&[1, index], &[2, 0], &[Boundary::Error, Boundary::Constant]),
+            Ok(None)
+        );
+    }
+    assert_eq!(
+        cart_to_lin_boundary(&[2, 0], &[2, 0], &[Boundary::Error, Boundary::Constant]),
+        Err(IndexError::OutOfBounds {
+            axis: 0,
+            index: 2,
+            bound: 2
+        })
+    );
+
+    assert_eq!(
+        cart_to_lin_boundary(&[0, 0], &[2, 3], &[Boundary::Wrap]),
+        Err(IndexError::DimensionMismatch {
+            expected: 2,
+            got: 1
+        })
+    );
+    assert_eq!(
+        cart_to_lin_boundary(&[0], &[2, 3], &[Boundary::Wrap; 2]),
+        Err(IndexError::DimensionMismatch {
+            expected: 2,
+            got: 1
+        })
+    );
+    assert_eq!(cart_to_lin_boundary(&[], &[], &[]), Ok(Some(0)));
+}
+
+#[test]
+fn test_overflow() {
+    let max = isize::MAX as usize;
+    assert_eq!(
+        cart_to_lin_boundary(&[0, 0], &[usize::MAX, 2], &[Boundary::Wrap; 2]),
+        Err(IndexError::Overflow)
+    );
+    // Shapes whose number of elements fits into an usize, but not into an isize
+    assert_eq!(
+        cart_to_lin_boundary(&[0, 0], &[max / 2 + 1, 2], &[Boundary::Wrap; 2]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        cart_to_lin_boundary(&[0, 0], &[max + 1, 0], &[Boundary::Constant; 2]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        cart_to_lin_boundary(&[-1], &[max], &[Boundary::Wrap]),
+        Ok(Some(max - 1))
+    );
+}
+
+#[test]
+fn test_extreme_indices() {
+    // The period of reflecting axes larger than half of the index range does not fit into an isize
+    let max = isize::MAX;
+    let sizes = [1, 2, 3, max / 2, max / 2 + 1, max / 2 + 2, max - 1, max];
+    let indices = [
+        isize::MIN,
+        isize::MIN + 1,
+        -max / 2,
+        -2,
+        -1,
+        0,
+        1,
+        max / 2,
+        max - 1,
+        max,
+    ];
+    for size in sizes {
+        for index in indices {
+            for mode in [Boundary::Wrap, Boundary::Clamp, Boundary::Reflect] {
+                let expected = reference_resolve(index as i128, size as i128, mode)
+                    .unwrap()
+                    .map(|resolved| resolved as usize);
+                assert_eq!(
+                    cart_to_lin_boundary(&[index], &[size as usize], &[mode]),
+                    Ok(expected),
+                    "index {index}, size {size}, mode {mode:?}"
+                );
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_reference(
+        dim_size in prop::array::uniform3(0usize..8),
+        indices in prop::array::uniform3(-30isize..30),
+        boundary in prop::array::uniform3(mode_strategy()),
+    ) {
+        let resolved: Vec<_> = (0..3)
+            .map(|axis| reference_resolve(indices[axis] as i128, dim_size[axis] as i128, boundary[axis]))
+            .collect();
+        let expected = match resolved.iter().position(Result::is_err) {
+            Some(axis) => Err(IndexError::OutOfBounds {
+                axis,
+                index: indices[axis],
+                bound: dim_size[axis] as isize,
+            }),
+            None => {
+                let resolved: Option<Vec<usize>> = resolved
+                    .into_iter()
+                    .map(|resolved| resolved.unwrap().map(|index| index as usize))
+                    .collect();
+                Ok(resolved.map(|cart| cart_to_lin(&cart, &dim_size).unwrap()))
+            }
+        };
+        prop_assert_eq!(cart_to_lin_boundary(&indices, &dim_size, &boundary), expected);
+    }
+
+    #[test]
+    fn prop_in_bounds_like_cart_to_lin(
+        dim_size in prop::array::uniform3(1usize..8),
+        seed in prop::array::uniform3(any::<usize>()),
+        boundary in prop::array::uniform3(mode_strategy()),
+    ) {
+        let cart = [0, 1, 2].map(|axis| seed[axis] % dim_size[axis]);
+        let indices = cart.map(|index| index as isize);
+        prop_assert_eq!(
+            cart_to_lin_boundary(&indices, &dim_size, &boundary),
+            Ok(Some(cart_to_lin(&cart, &dim_size).unwrap()))
+        );
+    }
+}
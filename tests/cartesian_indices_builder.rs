@@ -0,0 +1,149 @@
+use cart_lin::{CartesianIndices, CartesianIndicesBuilder, IndexError, PermutedLayout};
+use proptest::prelude::*;
+
+/// Reference implementation: sorts the default (row-major, ascending) traversal by the traversal key.
+fn sorted_reference(
+    bounds: [[usize; 3]; 3],
+    order: [usize; 3],
+    reversed: [bool; 3],
+) -> Vec<[usize; 3]> {
+    let mut indices: Vec<[usize; 3]> = CartesianIndices::from_bounds_with_step(bounds)
+        .unwrap()
+        .collect();
+    indices.sort_by_key(|cart| {
+        order.map(|axis| {
+            let index = cart[axis] as i64;
+            if reversed[axis] { -index } else { index }
+        })
+    });
+    indices
+}
+
+fn bounds_with_step_strategy() -> impl Strategy<Value = [[usize; 3]; 3]> {
+    [
+        (0usize..5, 1usize..7, 1usize..4),
+        (0usize..5, 1usize..7, 1usize..4),
+        (0usize..5, 1usize..7, 1usize..4),
+    ]
+    .prop_map(|axes| axes.map(|(start, len, step)| [start, start + len, step]))
+}
+
+fn order_strategy() -> impl Strategy<Value = [usize; 3]> {
+    Just(vec![0usize, 1, 2])
+        .prop_shuffle()
+        .prop_map(|order| [order[0], order[1], order[2]])
+}
+
+#[test]
+fn test_column_major() {
+    let cartiter = CartesianIndicesBuilder::new([2, 3])
+        .order(PermutedLayout::column_major())
+        .build()
+        .unwrap();
+    assert_eq!(cartiter.len(), 6);
+    assert_eq!(
+        cartiter.collect::<Vec<_>>(),
+        [[0, 0], [1, 0], [0, 1], [1, 1], [0, 2], [1, 2]]
+    );
+}
+
+#[test]
+fn test_reverse() {
+    let cartiter = CartesianIndicesBuilder::from_bounds([[1, 3], [2, 5]])
+        .reverse(0)
+        .build()
+        .unwrap();
+    assert_eq!(
+        cartiter.collect::<Vec<_>>(),
+        [[2, 2], [2, 3], [2, 4], [1, 2], [1, 3], [1, 4]]
+    );
+
+    // Reversing twice restores the ascending order
+    let cartiter = CartesianIndicesBuilder::new([2, 2])
+        .reverse(1)
+        .reverse(1)
+        .build()
+        .unwrap();
+    assert!(cartiter.eq(CartesianIndices::new([2, 2])));
+
+    // Descending steps start at the largest reachable index
+    let cartiter = CartesianIndicesBuilder::<1, u8>::from_bounds_with_step([[0, 255, 100]])
+        .reverse(0)
+        .build()
+        .unwrap();
+    assert_eq!(cartiter.collect::<Vec<_>>(), [[200], [100], [0]]);
+
+    // Negative bounds of signed types
+    let cartiter = CartesianIndicesBuilder::<1, i8>::from_bounds([[-128, -125]])
+        .reverse(0)
+        .build()
+        .unwrap();
+    assert_eq!(cartiter.collect::<Vec<_>>(), [[-126], [-127], [-128]]);
+}
+
+#[test]
+fn test_empty_and_errors() {
+    let mut cartiter = CartesianIndicesBuilder::new([2, 0, 3])
+        .order(PermutedLayout::column_major())
+        .reverse(2)
+        .build()
+        .unwrap();
+    assert_eq!(cartiter.len(), 0);
+    assert_eq!(cartiter.next(), None);
+    assert_eq!(cartiter.next_back(), None);
+
+    assert_eq!(
+        CartesianIndicesBuilder::<2, i32>::new([2, -1])
+            .build()
+            .unwrap_err(),
+        IndexError::NegativeDimension { axis: 1, size: -1 }
+    );
+    assert_eq!(
+        CartesianIndicesBuilder::from_bounds_with_step([[0, 2, 0]])
+            .build()
+            .unwrap_err(),
+        IndexError::InvalidStep { axis: 0, step: 0 }
+    );
+    assert_eq!(
+        CartesianIndicesBuilder::from_bounds([[0, usize::MAX], [0, 2]])
+            .build()
+            .unwrap_err(),
+        IndexError::Overflow
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_reverse_invalid_axis() {
+    let _ = CartesianIndicesBuilder::new([2, 2]).reverse(2);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_sorted_reference(
+        bounds in bounds_with_step_strategy(),
+        order in order_strategy(),
+        reversed in any::<[bool; 3]>(),
+    ) {
+        let reference = sorted_reference(bounds, order, reversed);
+        let mut builder = CartesianIndicesBuilder::from_bounds_with_step(bounds)
+            .order(PermutedLayout::new(order).unwrap());
+        for (axis, reversed) in reversed.into_iter().enumerate() {
+            if reversed {
+                builder = builder.reverse(axis);
+            }
+        }
+        let cartiter = builder.build().unwrap();
+        prop_assert_eq!(cartiter.len(), reference.len());
+        prop_assert_eq!(cartiter.clone().collect::<Vec<_>>(), reference.clone());
+
+        let mut reversed_reference = reference.clone();
+        reversed_reference.reverse();
+        prop_assert_eq!(cartiter.clone().rev().collect::<Vec<_>>(), reversed_reference);
+
+        for (n, expected) in reference.iter().enumerate() {
+            prop_assert_eq!(cartiter.clone().nth(n), Some(*expected));
+            prop_assert_eq!(cartiter.clone().nth_back(reference.len() - n - 1), Some(*expected));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "alloc")]
+
+use cart_lin::{CartesianIndices, CartesianIndicesDyn, IndexError};
+use proptest::prelude::*;
+
+fn bounds_strategy() -> impl Strategy<Value = [[usize; 2]; 3]> {
+    [
+        (0usize..5, 1usize..5),
+        (0usize..5, 1usize..5),
+        (0usize..5, 1usize..5),
+    ]
+    .prop_map(|axes| axes.map(|(start, len)| [start, start + len]))
+}
+
+#[test]
+fn test_dim_size() {
+    let mut cartiter = CartesianIndicesDyn::new(&[2, 1, 3]);
+    assert_eq!(cartiter.ndim(), 3);
+    assert_eq!(cartiter.len(), 6);
+    assert_eq!(cartiter.next_slice(), Some([0, 0, 0].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([0, 0, 1].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([0, 0, 2].as_slice()));
+    assert_eq!(cartiter.next_slice(), Some([1, 0, 0].as_slice()));
+    assert_eq!(cartiter.len(), 2);
+
+    let mut indices = [7; 3];
+    assert!(cartiter.next_into(&mut indices));
+    assert_eq!(indices, [1, 0, 1]);
+    assert_eq!(cartiter.next(), Some(vec![1, 0, 2]));
+    assert!(!cartiter.next_into(&mut indices));
+    assert_eq!(indices, [1, 0, 1]);
+    assert_eq!(cartiter.next_slice(), None);
+    assert_eq!(cartiter.next(), None);
+}
+
+#[test]
+fn test_empty() {
+    // A dimension of size zero
+    let mut cartiter = CartesianIndicesDyn::new(&[2, 0, 3]);
+    assert_eq!(cartiter.len(), 0);
+    assert_eq!(cartiter.next_slice(), None);
+
+    // Zero dimensions: a single element without any indices (like CartesianIndices::<0>)
+    let all: Vec<Vec<usize>> = CartesianIndicesDyn::new(&[]).collect();
+    assert_eq!(all, [Vec::<usize>::new()]);
+    assert_eq!(CartesianIndices::<0>::new([]).count(), 1);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        CartesianIndicesDyn::from_bounds(&[[0, 2], [3, 1]]).unwrap_err(),
+        IndexError::InvalidBounds {
+            axis: 1,
+            start: 3,
+            end: 1
+        }
+    );
+    assert_eq!(
+        CartesianIndicesDyn::from_bounds(&[[0, usize::MAX], [0, 2]]).unwrap_err(),
+        IndexError::Overflow
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_next_into_wrong_length() {
+    let mut cartiter = CartesianIndicesDyn::new(&[2, 3]);
+    cartiter.next_into(&mut [0; 3]);
+}
+
+#[test]
+fn test_index_type() {
+    let all: Vec<Vec<i32>> = CartesianIndicesDyn::from_bounds(&[[-1, 1], [0, 1]])
+        .unwrap()
+        .collect();
+    assert_eq!(all, [[-1, 0], [0, 0]]);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_fixed(bounds in bounds_strategy()) {
+        let fixed: Vec<[usize; 3]> = CartesianIndices::from_bounds(bounds).unwrap().collect();
+
+        let mut cartiter = CartesianIndicesDyn::from_bounds(&bounds).unwrap();
+        prop_assert_eq!(cartiter.len(), fixed.len());
+        for expected in fixed.iter() {
+            prop_assert_eq!(cartiter.next_slice(), Some(expected.as_slice()));
+        }
+        prop_assert_eq!(cartiter.next_slice(), None);
+
+        let owned: Vec<Vec<usize>> = CartesianIndicesDyn::from_bounds(&bounds).unwrap().collect();
+        prop_assert_eq!(owned, fixed.iter().map(|cart| cart.to_vec()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn prop_nth(bounds in bounds_strategy(), consumed in 0usize..30, n in 0usize..30) {
+        let mut fixed = CartesianIndices::from_bounds(bounds).unwrap();
+        let mut cartiter = CartesianIndicesDyn::from_bounds(&bounds).unwrap();
+        for _ in 0..consumed {
+            fixed.next();
+            cartiter.next_slice();
+        }
+        prop_assert_eq!(cartiter.nth(n), fixed.nth(n).map(|cart| cart.to_vec()));
+        let expected = fixed.next();
+        prop_assert_eq!(cartiter.next_slice(), expected.as_ref().map(|cart| cart.as_slice()));
+        prop_assert_eq!(cartiter.len(), fixed.len());
+    }
+}
@@ -1,4 +1,5 @@
-use cart_lin::CartesianIndices;
+use cart_lin::{CartesianIndices, IndexError};
+use proptest::prelude::*;
 
 #[test]
 fn test_cartesian_product_2d() {
@@ -22,10 +23,10 @@ fn test_cartesian_product_2d() {
         assert_eq!(product.next(), Some([1, 2]));
         assert_eq!(product.next(), None);
     }
-    assert!(CartesianIndices::from_bounds([[0, 0], [0, 3]]).is_none());
-    assert!(CartesianIndices::from_bounds([[1, 0], [0, 3]]).is_none());
-    assert!(CartesianIndices::from_bounds([[1, 0], [3, 3]]).is_none());
-    assert!(CartesianIndices::from_bounds([[1, 0], [3, 2]]).is_none());
+    assert!(CartesianIndices::from_bounds([[0, 0], [0, 3]]).is_err());
+    assert!(CartesianIndices::from_bounds([[1, 0], [0, 3]]).is_err());
+    assert!(CartesianIndices::from_bounds([[1, 0], [3, 3]]).is_err());
+    assert!(CartesianIndices::from_bounds([[1, 0], [3, 2]]).is_err());
 }
 
 #[test]
@@ -59,3 +60,272 @@ fn test_cartesian_product_3d() {
         assert_eq!(product.next(), None);
     }
 }
+
+/// Naive reference implementation using nested loops.
+fn nested_loops(bounds: [[usize; 2]; 3]) -> Vec<[usize; 3]> {
+    let mut indices = Vec::new();
+    for i in bounds[0][0]..bounds[0][1] {
+        for j in bounds[1][0]..bounds[1][1] {
+            for k in bounds[2][0]..bounds[2][1] {
+                indices.push([i, j, k]);
+            }
+        }
+    }
+    indices
+}
+
+fn bounds_strategy() -> impl Strategy<Value = [[usize; 2]; 3]> {
+    [
+        (0usize..5, 1usize..5),
+        (0usize..5, 1usize..5),
+        (0usize..5, 1usize..5),
+    ]
+    .prop_map(|axes| axes.map(|(start, len)| [start, start + len]))
+}
+
+#[test]
+fn test_nth_after_partial_consumption() {
+    let mut product = CartesianIndices::new([2, 3]);
+    assert_eq!(product.next(), Some([0, 0]));
+    assert_eq!(product.next(), Some([0, 1]));
+
+    // Advances relative to the current position instead of jumping to the absolute position
+    assert_eq!(product.nth(1), Some([1, 0]));
+    assert_eq!(product.next(), Some([1, 1]));
+    assert_eq!(product.nth(5), None);
+    assert_eq!(product.next(), None);
+
+    // Never moves backwards
+    let mut product = CartesianIndices::new([2, 3]);
+    assert_eq!(product.nth(4), Some([1, 1]));
+    assert_eq!(product.next(), Some([1, 2]));
+    assert_eq!(product.next(), None);
+
+    // Overflow-safe clamping
+    let mut product = CartesianIndices::<2, u8>::new([3, 3]);
+    assert_eq!(product.nth(usize::MAX), None);
+    assert_eq!(product.len(), 0);
+    let mut product = CartesianIndices::<2, u8>::new([3, 3]);
+    assert_eq!(product.nth(256), None);
+}
+
+#[test]
+fn test_size_hint() {
+    let mut product = CartesianIndices::new([2, 3]);
+    assert_eq!(product.size_hint(), (6, Some(6)));
+    product.next();
+    assert_eq!(product.len(), 5);
+    product.nth(3);
+    assert_eq!(product.len(), 1);
+    product.next();
+    assert_eq!(product.size_hint(), (0, Some(0)));
+
+    // The number of elements does not fit into an usize on any target
+    let product = CartesianIndices::<2, u128>::new([1 << 64, 2]);
+    assert_eq!(product.size_hint(), (usize::MAX, None));
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_nested_loops(bounds in bounds_strategy()) {
+        let reference = nested_loops(bounds);
+        let product = CartesianIndices::from_bounds(bounds).unwrap();
+        prop_assert_eq!(product.len(), reference.len());
+        prop_assert_eq!(product.collect::<Vec<_>>(), reference);
+    }
+
+    #[test]
+    fn prop_len_after_each_step(bounds in bounds_strategy()) {
+        let reference = nested_loops(bounds);
+        let mut product = CartesianIndices::from_bounds(bounds).unwrap();
+        for consumed in 0..=reference.len() {
+            prop_assert_eq!(product.size_hint(), (reference.len() - consumed, Some(reference.len() - consumed)));
+            product.next();
+        }
+        // Fused
+        prop_assert_eq!(product.next(), None);
+        prop_assert_eq!(product.len(), 0);
+    }
+
+    #[test]
+    fn prop_nth(bounds in bounds_strategy(), consumed in 0usize..30, n in 0usize..30) {
+        let reference = nested_loops(bounds);
+        let mut product = CartesianIndices::from_bounds(bounds).unwrap();
+        for _ in 0..consumed {
+            product.next();
+        }
+        prop_assert_eq!(product.nth(n), reference.get(consumed + n).copied());
+        prop_assert_eq!(product.next(), reference.get(consumed + n + 1).copied());
+        prop_assert_eq!(product.len(), reference.len().saturating_sub(consumed + n + 2));
+    }
+
+    #[test]
+    fn prop_skip_and_step_by(bounds in bounds_strategy(), consumed in 0usize..10, skip in 0usize..10, step in 1usize..10) {
+        let reference = nested_loops(bounds);
+        let mut product = CartesianIndices::from_bounds(bounds).unwrap();
+        for _ in 0..consumed {
+            product.next();
+        }
+        let expected: Vec<_> = reference.iter().copied().skip(consumed).skip(skip).step_by(step).collect();
+        prop_assert_eq!(product.skip(skip).step_by(step).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn test_double_ended() {
+    let reversed: Vec<_> = CartesianIndices::from_bounds([[1, 3], [2, 5]])
+        .unwrap()
+        .rev()
+        .collect();
+    assert_eq!(reversed, [[2, 4], [2, 3], [2, 2], [1, 4], [1, 3], [1, 2]]);
+
+    // Front and back cursor meet in the middle
+    let mut product = CartesianIndices::new([1, 3]);
+    assert_eq!(product.next_back(), Some([0, 2]));
+    assert_eq!(product.next(), Some([0, 0]));
+    assert_eq!(product.len(), 1);
+    assert_eq!(product.next_back(), Some([0, 1]));
+    assert_eq!(product.next(), None);
+    assert_eq!(product.next_back(), None);
+
+    // Overflow-safe clamping
+    let mut product = CartesianIndices::<2, i8>::new([3, 3]);
+    assert_eq!(product.nth_back(usize::MAX), None);
+    assert_eq!(product.next(), None);
+}
+
+proptest! {
+    #[test]
+    fn prop_rev_matches_nested_loops(bounds in bounds_strategy()) {
+        let mut reference = nested_loops(bounds);
+        reference.reverse();
+        let product = CartesianIndices::from_bounds(bounds).unwrap();
+        prop_assert_eq!(product.rev().collect::<Vec<_>>(), reference);
+    }
+
+    #[test]
+    fn prop_mixed_front_and_back(bounds in bounds_strategy(), steps in prop::collection::vec((any::<bool>(), 0usize..4), 0..40)) {
+        let reference = nested_loops(bounds);
+        let mut front = 0;
+        let mut back = reference.len();
+        let mut product = CartesianIndices::from_bounds(bounds).unwrap();
+        for (from_back, n) in steps {
+            if from_back {
+                let expected = if front + n < back {
+                    back -= n + 1;
+                    Some(reference[back])
+                } else {
+                    back = front;
+                    None
+                };
+                prop_assert_eq!(product.nth_back(n), expected);
+            } else {
+                let expected = if front + n < back {
+                    front += n + 1;
+                    Some(reference[front - 1])
+                } else {
+                    front = back;
+                    None
+                };
+                prop_assert_eq!(product.nth(n), expected);
+            }
+            prop_assert_eq!(product.len(), back - front);
+        }
+        prop_assert_eq!(product.collect::<Vec<_>>(), reference[front..back].to_vec());
+    }
+}
+
+/// Naive reference implementation using nested loops with `step_by`.
+fn nested_loops_with_step(bounds: [[usize; 3]; 3]) -> Vec<[usize; 3]> {
+    let mut indices = Vec::new();
+    for i in (bounds[0][0]..bounds[0][1]).step_by(bounds[0][2]) {
+        for j in (bounds[1][0]..bounds[1][1]).step_by(bounds[1][2]) {
+            for k in (bounds[2][0]..bounds[2][1]).step_by(bounds[2][2]) {
+                indices.push([i, j, k]);
+            }
+        }
+    }
+    indices
+}
+
+fn bounds_with_step_strategy() -> impl Strategy<Value = [[usize; 3]; 3]> {
+    [
+        (0usize..5, 1usize..9, 1usize..5),
+        (0usize..5, 1usize..9, 1usize..5),
+        (0usize..5, 1usize..9, 1usize..5),
+    ]
+    .prop_map(|axes| axes.map(|(start, len, step)| [start, start + len, step]))
+}
+
+#[test]
+fn test_step() {
+    let product = CartesianIndices::from_bounds_with_step([[1, 6, 2], [0, 3, 5]]).unwrap();
+    assert_eq!(product.len(), 3);
+    assert_eq!(product.collect::<Vec<_>>(), [[1, 0], [3, 0], [5, 0]]);
+
+    // A step of one is equivalent to from_bounds
+    let stepped: Vec<_> = CartesianIndices::from_bounds_with_step([[1, 3, 1], [2, 5, 1]])
+        .unwrap()
+        .collect();
+    let unit: Vec<_> = CartesianIndices::from_bounds([[1, 3], [2, 5]])
+        .unwrap()
+        .collect();
+    assert_eq!(stepped, unit);
+
+    // Stepping beyond the end of the index type
+    let product = CartesianIndices::<1, u8>::from_bounds_with_step([[0, 255, 200]]).unwrap();
+    assert_eq!(product.clone().collect::<Vec<_>>(), [[0], [200]]);
+    assert_eq!(product.rev().collect::<Vec<_>>(), [[200], [0]]);
+    let product = CartesianIndices::<1, i8>::from_bounds_with_step([[20, 127, 100]]).unwrap();
+    assert_eq!(product.collect::<Vec<_>>(), [[20], [120]]);
+
+    assert_eq!(
+        CartesianIndices::from_bounds_with_step([[0, 2, 1], [0, 3, 0]]).unwrap_err(),
+        IndexError::InvalidStep { axis: 1, step: 0 }
+    );
+    assert_eq!(
+        CartesianIndices::<1, i32>::from_bounds_with_step([[0, 3, -1]]).unwrap_err(),
+        IndexError::InvalidStep { axis: 0, step: -1 }
+    );
+    assert_eq!(
+        CartesianIndices::from_bounds_with_step([[2, 2, 1]]).unwrap_err(),
+        IndexError::InvalidBounds {
+            axis: 0,
+            start: 2,
+            end: 2
+        }
+    );
+    assert_eq!(
+        CartesianIndices::<2, u8>::from_bounds_with_step([[0, 255, 8], [0, 255, 8]]).unwrap_err(),
+        IndexError::Overflow
+    );
+}
+
+proptest! {
+    #[test]
+    fn prop_step_matches_nested_loops(bounds in bounds_with_step_strategy()) {
+        let reference = nested_loops_with_step(bounds);
+        let product = CartesianIndices::from_bounds_with_step(bounds).unwrap();
+        prop_assert_eq!(product.len(), reference.len());
+        prop_assert_eq!(product.clone().collect::<Vec<_>>(), reference.clone());
+
+        let mut reversed = reference;
+        reversed.reverse();
+        prop_assert_eq!(product.rev().collect::<Vec<_>>(), reversed);
+    }
+
+    #[test]
+    fn prop_step_nth(bounds in bounds_with_step_strategy(), consumed in 0usize..30, n in 0usize..30, n_back in 0usize..30) {
+        let reference = nested_loops_with_step(bounds);
+        let mut product = CartesianIndices::from_bounds_with_step(bounds).unwrap();
+        for _ in 0..consumed {
+            product.next();
+        }
+        prop_assert_eq!(product.nth(n), reference.get(consumed + n).copied());
+
+        let front = (consumed + n + 1).min(reference.len());
+        let expected = reference.len().checked_sub(n_back + 1).filter(|back| *back >= front);
+        prop_assert_eq!(product.nth_back(n_back), expected.map(|back| reference[back]));
+        prop_assert_eq!(product.len(), expected.map_or(0, |back| back - front));
+    }
+}
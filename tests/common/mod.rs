@@ -0,0 +1,66 @@
+//! Helpers shared by the tests of the double-ended iterators over layouts.
+
+use core::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+/// Checks an iterator against the expected items, using `nth` (or `nth_back` if the flag is set) with the
+/// given step sizes and then completing the traversal from both ends.
+pub fn check_random_access<I>(
+    cartiter: I,
+    reference: &[I::Item],
+    steps: &[(bool, usize)],
+) -> Result<(), TestCaseError>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    I::Item: Copy + PartialEq + Debug,
+{
+    prop_assert_eq!(cartiter.len(), reference.len());
+    prop_assert_eq!(cartiter.clone().collect::<Vec<_>>(), reference.to_vec());
+    prop_assert_eq!(
+        cartiter.clone().rev().collect::<Vec<_>>(),
+        reference.iter().rev().copied().collect::<Vec<_>>()
+    );
+
+    let mut cartiter = cartiter;
+    let mut front = 0;
+    let mut back = reference.len();
+    for (from_back, n) in steps.iter().copied() {
+        if from_back {
+            let expected = if front + n < back {
+                back -= n + 1;
+                Some(reference[back])
+            } else {
+                back = front;
+                None
+            };
+            prop_assert_eq!(cartiter.nth_back(n), expected);
+        } else {
+            let expected = if front + n < back {
+                front += n + 1;
+                Some(reference[front - 1])
+            } else {
+                front = back;
+                None
+            };
+            prop_assert_eq!(cartiter.nth(n), expected);
+        }
+        prop_assert_eq!(cartiter.len(), back - front);
+    }
+
+    // Incremental updates after random access
+    prop_assert_eq!(
+        cartiter.clone().collect::<Vec<_>>(),
+        reference[front..back].to_vec()
+    );
+    prop_assert_eq!(
+        cartiter.rev().collect::<Vec<_>>(),
+        reference[front..back]
+            .iter()
+            .rev()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
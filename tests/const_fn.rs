@@ -0,0 +1,93 @@
+use cart_lin::{
+    CartesianIndices, cart_to_lin_unchecked, cart_to_lin_unchecked_const, lin_to_cart_unchecked,
+    lin_to_cart_unchecked_const,
+};
+use proptest::prelude::*;
+
+const DIM_SIZE: [usize; 3] = [2, 3, 4];
+
+const TABLE: [[usize; 3]; 24] = {
+    let mut table = [[0; 3]; 24];
+    let mut index = 0;
+    while index < table.len() {
+        table[index] = lin_to_cart_unchecked_const(index, &DIM_SIZE);
+        index += 1;
+    }
+    table
+};
+
+const LINEAR: [usize; 24] = {
+    let mut linear = [0; 24];
+    let mut index = 0;
+    while index < linear.len() {
+        linear[index] = cart_to_lin_unchecked_const(&TABLE[index], &DIM_SIZE);
+        index += 1;
+    }
+    linear
+};
+
+#[test]
+fn test_lookup_table() {
+    for (index, cart) in CartesianIndices::new(DIM_SIZE).enumerate() {
+        assert_eq!(TABLE[index], cart);
+        assert_eq!(LINEAR[index], index);
+    }
+}
+
+#[test]
+fn test_identical_to_runtime() {
+    for dim_size in [[1, 1, 1], [5, 1, 7], [3, 3, 3], [2, 3, 4]] {
+        // Including out-of-bounds linear indices
+        for index in 0..200 {
+            let cart = lin_to_cart_unchecked(index, &dim_size);
+            assert_eq!(lin_to_cart_unchecked_const(index, &dim_size), cart);
+            assert_eq!(
+                cart_to_lin_unchecked_const(&cart, &dim_size),
+                cart_to_lin_unchecked(&cart, &dim_size)
+            );
+        }
+    }
+
+    // Mismatched lengths
+    assert_eq!(
+        cart_to_lin_unchecked_const(&[1, 2], &[2, 3, 4]),
+        cart_to_lin_unchecked(&[1, 2], &[2, 3, 4])
+    );
+    assert_eq!(
+        cart_to_lin_unchecked_const(&[1, 2, 3], &[3, 4]),
+        cart_to_lin_unchecked(&[1, 2, 3], &[3, 4])
+    );
+}
+
+#[test]
+fn test_overflow_wraps() {
+    // Out-of-bounds indices and overflowing shapes wrap around like the generic functions
+    let dim_size = [usize::MAX, 3];
+    let cart = [usize::MAX, usize::MAX];
+    assert_eq!(
+        cart_to_lin_unchecked_const(&cart, &dim_size),
+        cart_to_lin_unchecked(&cart, &dim_size)
+    );
+    assert_eq!(
+        cart_to_lin_unchecked_const(&cart, &dim_size),
+        usize::MAX.wrapping_mul(3).wrapping_add(usize::MAX)
+    );
+}
+
+proptest! {
+    #[test]
+    fn prop_identical_to_runtime(
+        dim_size in prop::array::uniform3(prop_oneof![1usize..10, any::<usize>().prop_map(|size| size.max(1))]),
+        cart in prop::array::uniform3(prop_oneof![0usize..10, any::<usize>()]),
+        index in any::<usize>(),
+    ) {
+        prop_assert_eq!(
+            cart_to_lin_unchecked_const(&cart, &dim_size),
+            cart_to_lin_unchecked(&cart, &dim_size)
+        );
+        prop_assert_eq!(
+            lin_to_cart_unchecked_const(index, &dim_size),
+            lin_to_cart_unchecked(index, &dim_size)
+        );
+    }
+}
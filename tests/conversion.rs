@@ -1,4 +1,4 @@
-use cart_lin::{cart_to_lin, lin_to_cart, lin_to_cart_dyn};
+use cart_lin::{ColumnMajor, Order, cart_to_lin, lin_to_cart, lin_to_cart_dyn};
 
 #[test]
 fn test_cart_to_lin_1d() {
@@ -58,10 +58,10 @@ fn test_cart_to_lin_3d() {
         assert_eq!(cart_to_lin(&[0, 1, 2], &[2, 3, 4]).unwrap(), 6);
         assert_eq!(cart_to_lin(&[1, 1, 2], &[2, 3, 4]).unwrap(), 18);
 
-        assert!(cart_to_lin(&[2, 1, 2], &[2, 3, 4]).is_none());
-        assert!(cart_to_lin(&[54, 1, 2], &[2, 3, 4]).is_none());
-        assert!(cart_to_lin(&[1, 3, 2], &[2, 3, 4]).is_none());
-        assert!(cart_to_lin(&[1, 0, 5], &[2, 3, 4]).is_none());
+        assert!(cart_to_lin(&[2, 1, 2], &[2, 3, 4]).is_err());
+        assert!(cart_to_lin(&[54, 1, 2], &[2, 3, 4]).is_err());
+        assert!(cart_to_lin(&[1, 3, 2], &[2, 3, 4]).is_err());
+        assert!(cart_to_lin(&[1, 0, 5], &[2, 3, 4]).is_err());
     }
 }
 
@@ -74,10 +74,10 @@ fn test_lin_to_cart_1d() {
     assert_eq!([4], cart_idx.unwrap());
 
     let cart_idx = lin_to_cart(5, &[5]);
-    assert!(cart_idx.is_none());
+    assert!(cart_idx.is_err());
 
     let cart_idx = lin_to_cart(7, &[5]);
-    assert!(cart_idx.is_none());
+    assert!(cart_idx.is_err());
 }
 
 #[test]
@@ -95,9 +95,9 @@ fn test_lin_to_cart_2d() {
     let cart_idx = lin_to_cart(5, &[2, 3]);
     assert_eq!([1, 2], cart_idx.unwrap());
     let cart_idx = lin_to_cart(6, &[2, 3]);
-    assert!(cart_idx.is_none());
+    assert!(cart_idx.is_err());
     let cart_idx = lin_to_cart(1243, &[2, 3]);
-    assert!(cart_idx.is_none());
+    assert!(cart_idx.is_err());
 }
 
 #[test]
@@ -125,7 +125,7 @@ fn test_lin_to_cart_3d() {
     let cart_idx = lin_to_cart(55, &[2, 4, 7]);
     assert_eq!([1, 3, 6], cart_idx.unwrap());
     let cart_idx = lin_to_cart(1243, &[2, 4, 7]);
-    assert!(cart_idx.is_none());
+    assert!(cart_idx.is_err());
 }
 
 #[test]
@@ -151,10 +151,10 @@ fn test_lin_to_cart_dyn_2d() {
     assert!(lin_to_cart_dyn(5, &[2, 3], buffer).is_ok());
     assert_eq!(&[1, 2], buffer);
 
-    assert!(!lin_to_cart_dyn(6, &[2, 3], buffer).is_ok());
+    assert!(lin_to_cart_dyn(6, &[2, 3], buffer).is_err());
     assert_eq!(&[1, 2], buffer); // Buffer is not changed
 
-    assert!(!lin_to_cart_dyn(1243, &[2, 3], buffer).is_ok());
+    assert!(lin_to_cart_dyn(1243, &[2, 3], buffer).is_err());
     assert_eq!(&[1, 2], buffer); // Buffer is not changed
 }
 
@@ -250,3 +250,27 @@ fn test_lin_to_cart_to_lin_3d() {
     let output = cart_to_lin(&lin_to_cart(input, &bounds).unwrap(), &bounds).unwrap();
     assert_eq!(input, output);
 }
+
+#[test]
+fn test_column_major_3d() {
+    // Column-major order is row-major order with reversed axes
+    let dim_size = [2, 4, 7];
+    let reversed = [7, 4, 2];
+    for lin in 0..56 {
+        let cart = ColumnMajor::lin_to_cart(lin, &dim_size).unwrap();
+        let mut cart_rev = cart;
+        cart_rev.reverse();
+        assert_eq!(lin_to_cart(lin, &reversed).unwrap(), cart_rev);
+        assert_eq!(ColumnMajor::cart_to_lin(&cart, &dim_size).unwrap(), lin);
+        assert_eq!(cart_to_lin(&cart_rev, &reversed).unwrap(), lin);
+    }
+    assert!(ColumnMajor::lin_to_cart(56, &dim_size).is_err());
+    assert!(ColumnMajor::cart_to_lin(&[2, 0, 0], &dim_size).is_err());
+    assert!(ColumnMajor::cart_to_lin(&[0, 0], &dim_size).is_err());
+
+    let mut buffer = [0, 0, 0];
+    assert!(ColumnMajor::lin_to_cart_dyn(9, &dim_size, &mut buffer).is_ok());
+    assert_eq!(buffer, [1, 0, 1]);
+    assert!(ColumnMajor::lin_to_cart_dyn(56, &dim_size, &mut buffer).is_err());
+    assert_eq!(buffer, [1, 0, 1]);
+}
@@ -0,0 +1,183 @@
+use cart_lin::{
+    CartesianIndices, CartesianIndicesBuilder, IndexError, PermutedLayout, cart_to_lin,
+};
+use proptest::prelude::*;
+
+const DIM_SIZE: [usize; 3] = [6, 7, 8];
+
+/// Bounds and steps of a box within `DIM_SIZE`.
+fn bounds_with_step_strategy() -> impl Strategy<Value = [[usize; 3]; 3]> {
+    [
+        (0usize..5, 1usize..4),
+        (0usize..6, 1usize..4),
+        (0usize..7, 1usize..4),
+    ]
+    .prop_flat_map(|axes| {
+        let ends = [
+            axes[0].0 + 1..DIM_SIZE[0] + 1,
+            axes[1].0 + 1..DIM_SIZE[1] + 1,
+            axes[2].0 + 1..DIM_SIZE[2] + 1,
+        ];
+        (Just(axes), ends)
+    })
+    .prop_map(|(axes, ends)| {
+        [
+            [axes[0].0, ends[0], axes[0].1],
+            [axes[1].0, ends[1], axes[1].1],
+            [axes[2].0, ends[2], axes[2].1],
+        ]
+    })
+}
+
+fn order_strategy() -> impl Strategy<Value = [usize; 3]> {
+    Just(vec![0usize, 1, 2])
+        .prop_shuffle()
+        .prop_map(|order| [order[0], order[1], order[2]])
+}
+
+#[test]
+fn test_sub_box() {
+    let all: Vec<_> = CartesianIndices::from_bounds([[1, 3], [2, 4]])
+        .unwrap()
+        .enumerate_linear([3, 4])
+        .unwrap()
+        .collect();
+    assert_eq!(all, [(6, [1, 2]), (7, [1, 3]), (10, [2, 2]), (11, [2, 3])]);
+
+    // Reversed axes and column-major traversal
+    let all: Vec<_> = CartesianIndicesBuilder::new([2, 2])
+        .order(PermutedLayout::column_major())
+        .reverse(0)
+        .build()
+        .unwrap()
+        .enumerate_linear([2, 3])
+        .unwrap()
+        .collect();
+    assert_eq!(all, [(3, [1, 0]), (0, [0, 0]), (4, [1, 1]), (1, [0, 1])]);
+
+    // Starting from a partially consumed iterator
+    let mut cartiter = CartesianIndices::new([2, 3]);
+    cartiter.nth(3);
+    let mut enumerate = cartiter.enumerate_linear([2, 3]).unwrap();
+    assert_eq!(enumerate.len(), 2);
+    assert_eq!(enumerate.next(), Some((4, [1, 1])));
+    assert_eq!(enumerate.next_back(), Some((5, [1, 2])));
+    assert_eq!(enumerate.next(), None);
+}
+
+#[test]
+fn test_signed() {
+    let all: Vec<_> = CartesianIndicesBuilder::<2, i8>::from_bounds([[0, 2], [1, 3]])
+        .reverse(1)
+        .build()
+        .unwrap()
+        .enumerate_linear([2, 3])
+        .unwrap()
+        .collect();
+    assert_eq!(all, [(2, [0, 2]), (1, [0, 1]), (5, [1, 2]), (4, [1, 1])]);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        CartesianIndices::from_bounds([[0, 2], [1, 4]])
+            .unwrap()
+            .enumerate_linear([2, 3])
+            .unwrap_err(),
+        IndexError::OutOfBounds {
+            axis: 1,
+            index: 3,
+            bound: 3
+        }
+    );
+    assert_eq!(
+        CartesianIndices::<1, i32>::from_bounds([[-1, 1]])
+            .unwrap()
+            .enumerate_linear([2])
+            .unwrap_err(),
+        IndexError::OutOfBounds {
+            axis: 0,
+            index: -1,
+            bound: 2
+        }
+    );
+    assert_eq!(
+        CartesianIndices::new([1, 1])
+            .enumerate_linear([usize::MAX, 2])
+            .unwrap_err(),
+        IndexError::Overflow
+    );
+
+    // An empty iterator fits into any parent array
+    assert_eq!(
+        CartesianIndices::new([0, 5])
+            .enumerate_linear([1, 1])
+            .unwrap()
+            .next(),
+        None
+    );
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_cart_to_lin(
+        bounds in bounds_with_step_strategy(),
+        order in order_strategy(),
+        reversed in any::<[bool; 3]>(),
+        steps in prop::collection::vec((any::<bool>(), 0usize..4), 0..40),
+    ) {
+        let mut builder = CartesianIndicesBuilder::from_bounds_with_step(bounds)
+            .order(PermutedLayout::new(order).unwrap());
+        for (axis, reversed) in reversed.into_iter().enumerate() {
+            if reversed {
+                builder = builder.reverse(axis);
+            }
+        }
+        let cartiter = builder.build().unwrap();
+        let reference: Vec<(usize, [usize; 3])> = cartiter
+            .clone()
+            .map(|cart| (cart_to_lin(&cart, &DIM_SIZE).unwrap(), cart))
+            .collect();
+
+        let enumerate = cartiter.enumerate_linear(DIM_SIZE).unwrap();
+        prop_assert_eq!(enumerate.clone().collect::<Vec<_>>(), reference.clone());
+        prop_assert_eq!(
+            enumerate.clone().rev().collect::<Vec<_>>(),
+            reference.iter().rev().copied().collect::<Vec<_>>()
+        );
+
+        // Random access from both ends
+        let mut enumerate = enumerate;
+        let mut front = 0;
+        let mut back = reference.len();
+        for (from_back, n) in steps {
+            if from_back {
+                let expected = if front + n < back {
+                    back -= n + 1;
+                    Some(reference[back])
+                } else {
+                    back = front;
+                    None
+                };
+                prop_assert_eq!(enumerate.nth_back(n), expected);
+            } else {
+                let expected = if front + n < back {
+                    front += n + 1;
+                    Some(reference[front - 1])
+                } else {
+                    front = back;
+                    None
+                };
+                prop_assert_eq!(enumerate.nth(n), expected);
+            }
+            prop_assert_eq!(enumerate.len(), back - front);
+        }
+
+        // Incremental updates after random access
+        prop_assert_eq!(enumerate.clone().collect::<Vec<_>>(), reference[front..back].to_vec());
+        prop_assert_eq!(
+            enumerate.rev().collect::<Vec<_>>(),
+            reference[front..back].iter().rev().copied().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(feature = "alloc")]
+use cart_lin::ShapeDyn;
+use cart_lin::{
+    CartesianIndices, ColumnMajor, IndexError, Order, PermutedLayout, Shape, StridedLayout,
+    cart_to_lin, lin_to_cart, lin_to_cart_dyn,
+};
+
+#[test]
+fn test_conversion_errors() {
+    assert_eq!(
+        cart_to_lin(&[0, 1], &[2, 3, 4]),
+        Err(IndexError::DimensionMismatch {
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        cart_to_lin(&[1, 3, 0], &[2, 3, 4]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 3,
+            bound: 3
+        })
+    );
+    assert_eq!(
+        ColumnMajor::cart_to_lin(&[1, 2, 4], &[2, 3, 4]),
+        Err(IndexError::OutOfBounds {
+            axis: 2,
+            index: 4,
+            bound: 4
+        })
+    );
+    assert_eq!(
+        lin_to_cart(24, &[2, 3, 4]),
+        Err(IndexError::LinearOutOfRange { index: 24, len: 24 })
+    );
+
+    let mut buffer = [0, 0];
+    assert_eq!(
+        lin_to_cart_dyn(0, &[2, 3, 4], &mut buffer),
+        Err(IndexError::DimensionMismatch {
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        lin_to_cart_dyn(7, &[2, 3], &mut buffer),
+        Err(IndexError::LinearOutOfRange { index: 7, len: 6 })
+    );
+}
+
+#[test]
+fn test_constructor_errors() {
+    assert_eq!(
+        CartesianIndices::from_bounds([[0, 2], [3, 1]]).unwrap_err(),
+        IndexError::InvalidBounds {
+            axis: 1,
+            start: 3,
+            end: 1
+        }
+    );
+    assert_eq!(
+        PermutedLayout::new([1, 1]),
+        Err(IndexError::InvalidPermutation { axis: 1 })
+    );
+}
+
+#[test]
+fn test_layout_errors() {
+    let layout = StridedLayout::new(1, [2, 2], [-2, 1]);
+    assert_eq!(
+        layout.cart_to_offset(&[0, 2]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 2,
+            bound: 2
+        })
+    );
+    assert_eq!(
+        layout.cart_to_offset(&[1, 0]),
+        Err(IndexError::NegativeOffset)
+    );
+    assert_eq!(
+        layout.offset_to_cart(3),
+        Err(IndexError::UnmappedOffset { offset: 3 })
+    );
+
+    let shape = Shape::new([2, 3]);
+    assert_eq!(
+        shape.to_cart(6),
+        Err(IndexError::LinearOutOfRange { index: 6, len: 6 })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_shape_dyn_errors() {
+    let shape = ShapeDyn::new(&[2, 3]);
+    assert_eq!(
+        shape.to_lin(&[1, 1, 1]),
+        Err(IndexError::DimensionMismatch {
+            expected: 2,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn test_display() {
+    let err: Box<dyn std::error::Error> =
+        Box::new(IndexError::LinearOutOfRange { index: 6, len: 6 });
+    assert_eq!(
+        err.to_string(),
+        "linear index 6 is out of range for 6 elements"
+    );
+    assert_eq!(
+        IndexError::<usize>::DimensionMismatch {
+            expected: 3,
+            got: 2
+        }
+        .to_string(),
+        "expected 3 dimensions, got 2"
+    );
+}
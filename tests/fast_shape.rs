@@ -0,0 +1,105 @@
+use cart_lin::{ColumnMajor, FastShape, Order, Shape, lin_to_cart, lin_to_cart_unchecked};
+
+/// Exhaustive comparison of all linear indices (plus some out-of-bounds ones) with the
+/// division-based implementation.
+fn compare_exhaustive<const N: usize>(dim_size: [usize; N]) {
+    let fast_shape = FastShape::new(dim_size);
+    let shape = Shape::new(dim_size);
+    let len = shape.len();
+    assert_eq!(fast_shape.len(), len);
+    for lin in 0..len + 2 * len.max(1) {
+        assert_eq!(fast_shape.to_cart(lin), lin_to_cart(lin, &dim_size));
+        assert_eq!(
+            fast_shape.to_cart_unchecked(lin),
+            lin_to_cart_unchecked(lin, &dim_size)
+        );
+        assert_eq!(
+            fast_shape.to_cart_unchecked(lin),
+            shape.to_cart_unchecked(lin)
+        );
+    }
+}
+
+#[test]
+fn test_exhaustive_1d_to_4d() {
+    for d0 in 1..=12 {
+        compare_exhaustive([d0]);
+        for d1 in 1..=12 {
+            compare_exhaustive([d0, d1]);
+        }
+    }
+    compare_exhaustive([3, 5, 7]);
+    compare_exhaustive([16, 1, 9]);
+    compare_exhaustive([2, 3, 4, 5]);
+    compare_exhaustive([7, 11, 13, 1]);
+    compare_exhaustive([1000, 1000]);
+}
+
+#[test]
+fn test_exhaustive_column_major() {
+    let dim_size = [7, 3, 10];
+    let fast_shape = FastShape::from_order::<ColumnMajor>(dim_size);
+    for lin in 0..300 {
+        assert_eq!(
+            fast_shape.to_cart(lin),
+            ColumnMajor::lin_to_cart(lin, &dim_size)
+        );
+    }
+}
+
+#[test]
+fn test_large_divisors_and_dividends() {
+    // Quotient and remainder are obtained by decoding with a two-dimensional shape
+    // whose leading dimension is large enough to hold all quotients
+    let mut divisors: Vec<usize> = vec![1, 2, 3, 5, 6, 7, 10, 641, 6700417, 274177];
+    for shift in 1..usize::BITS {
+        let power = 1usize << shift;
+        divisors.extend([power - 1, power, power + 1]);
+    }
+    divisors.extend([usize::MAX / 3, usize::MAX - 1, usize::MAX]);
+
+    // Simple linear congruential generator for reproducible pseudo-random dividends
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    let mut dividends: Vec<usize> = vec![0, 1, 2, usize::MAX - 1, usize::MAX];
+    for _ in 0..2000 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        dividends.push((state >> (state % 64)) as usize);
+        dividends.push(state as usize);
+    }
+
+    for divisor in divisors {
+        let fast_shape = FastShape::new([usize::MAX / divisor, divisor]);
+        let len = fast_shape.len();
+        for dividend in dividends.iter().copied() {
+            for n in [dividend, dividend / divisor * divisor, dividend % divisor] {
+                let n = n % len;
+                assert_eq!(
+                    fast_shape.to_cart(n).unwrap(),
+                    [n / divisor, n % divisor],
+                    "{n} / {divisor}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_empty() {
+    let fast_shape = FastShape::new([3, 0]);
+    assert!(fast_shape.is_empty());
+    assert!(fast_shape.to_cart(0).is_err());
+}
+
+#[test]
+fn test_zero_sized_axes_unchecked() {
+    // Axes of size zero are decoded like axes of size one, in both memory orders
+    for index in 0..40 {
+        let row_major = FastShape::new([2, 0, 3, 0]).to_cart_unchecked(index);
+        assert_eq!(row_major, [(index / 3) % 2, 0, index % 3, 0]);
+        let column_major =
+            FastShape::from_order::<ColumnMajor>([0, 2, 0, 3]).to_cart_unchecked(index);
+        assert_eq!(column_major, [0, index % 2, 0, (index / 2) % 3]);
+    }
+}
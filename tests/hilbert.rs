@@ -0,0 +1,221 @@
+use cart_lin::{
+    CartesianIndices, HilbertIndices, IndexError, cart_to_hilbert, cart_to_hilbert_unchecked,
+    hilbert_to_cart, hilbert_to_cart_unchecked,
+};
+use proptest::prelude::*;
+
+/// Reference implementation for two dimensions (rotation-based algorithm), with the first axis as `y`.
+fn reference_hilbert_2d(indices: [usize; 2], order: u32) -> usize {
+    let [mut y, mut x] = indices;
+    let side = 1 << order;
+    let mut distance = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let rx = usize::from(x & s > 0);
+        let ry = usize::from(y & s > 0);
+        distance += s * s * ((3 * ry) ^ rx);
+        if rx == 0 {
+            if ry == 1 {
+                x = side - 1 - x;
+                y = side - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    distance
+}
+
+/// Checks that the curve visits all cells of the hypercube exactly once, moving one step at a time.
+fn check_curve<const N: usize>(order: u32) {
+    let side = 1usize << order;
+    let len = side.pow(N as u32);
+    let mut visited = vec![false; len];
+    let mut previous: Option<[usize; N]> = None;
+    for distance in 0..len {
+        let cart: [usize; N] = hilbert_to_cart(distance, order).unwrap();
+        assert_eq!(cart_to_hilbert(&cart, order), Ok(distance));
+
+        let lin = cart_lin::cart_to_lin(&cart, &[side; N]).unwrap();
+        assert!(!visited[lin]);
+        visited[lin] = true;
+
+        if let Some(previous) = previous {
+            let step: usize = cart
+                .iter()
+                .zip(previous.iter())
+                .map(|(a, b)| a.abs_diff(*b))
+                .sum();
+            assert_eq!(step, 1);
+        } else {
+            assert_eq!(cart, [0; N]);
+        }
+        previous = Some(cart);
+    }
+}
+
+#[test]
+fn test_curve_properties() {
+    for order in 0..6 {
+        check_curve::<1>(order);
+        check_curve::<2>(order);
+        check_curve::<3>(order);
+    }
+    check_curve::<4>(3);
+    check_curve::<5>(2);
+}
+
+#[test]
+fn test_matches_reference_2d() {
+    for order in 0..6 {
+        for cart in CartesianIndices::new([1usize << order; 2]) {
+            assert_eq!(
+                cart_to_hilbert(&cart, order),
+                Ok(reference_hilbert_2d(cart, order))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        cart_to_hilbert(&[4usize, 0], 2),
+        Err(IndexError::OutOfBounds {
+            axis: 0,
+            index: 4,
+            bound: 4
+        })
+    );
+    assert_eq!(
+        cart_to_hilbert::<i32, 2>(&[0, -1], 2),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: -1,
+            bound: 4
+        })
+    );
+    assert_eq!(
+        hilbert_to_cart::<usize, 3>(64, 2),
+        Err(IndexError::LinearOutOfRange { index: 64, len: 64 })
+    );
+
+    // The number of cells must fit into the index type
+    assert_eq!(
+        cart_to_hilbert::<u8, 2>(&[0, 0], 4),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(hilbert_to_cart::<i8, 1>(0, 7), Err(IndexError::Overflow));
+    assert_eq!(
+        hilbert_to_cart::<i8, 1>(127, 6).unwrap_err(),
+        IndexError::LinearOutOfRange {
+            index: 127,
+            len: 64
+        }
+    );
+    assert_eq!(
+        cart_to_hilbert::<u8, 2>(&[15, 0], 3).unwrap_err(),
+        IndexError::OutOfBounds {
+            axis: 0,
+            index: 15,
+            bound: 8
+        }
+    );
+    assert_eq!(cart_to_hilbert::<u8, 2>(&[7, 0], 3), Ok(63));
+
+    // The unchecked functions do not panic for large orders
+    let _ = cart_to_hilbert_unchecked::<u8, 2>(&[255, 255], 200);
+    let _ = hilbert_to_cart_unchecked::<i8, 3>(-1, 200);
+}
+
+#[test]
+fn test_index_types() {
+    let order = 40;
+    let cart = [(1u128 << 40) - 1, 12345, 1 << 39];
+    let distance = cart_to_hilbert(&cart, order).unwrap();
+    assert_eq!(hilbert_to_cart(distance, order), Ok(cart));
+
+    for distance in 0..64i8 {
+        let cart: [i8; 3] = hilbert_to_cart(distance, 2).unwrap();
+        let expected: [usize; 3] = hilbert_to_cart(distance as usize, 2).unwrap();
+        assert_eq!(cart.map(|index| index as usize), expected);
+    }
+}
+
+#[test]
+fn test_iterator() {
+    let cartiter = HilbertIndices::new([4usize, 4]).unwrap();
+    let expected: Vec<[usize; 2]> = (0..16).map(|d| hilbert_to_cart(d, 2).unwrap()).collect();
+    assert_eq!(cartiter.collect::<Vec<_>>(), expected);
+
+    let mut cartiter = HilbertIndices::new([1usize, 1000]).unwrap();
+    assert_eq!(cartiter.len(), 1000);
+    assert_eq!(cartiter.next(), Some([0, 0]));
+    assert_eq!(cartiter.by_ref().last(), Some([0, 999]));
+    assert_eq!(cartiter.next(), None);
+
+    assert_eq!(HilbertIndices::new([3usize, 0, 2]).unwrap().count(), 0);
+    assert_eq!(
+        HilbertIndices::<0>::new([]).unwrap().collect::<Vec<_>>(),
+        [[]]
+    );
+    assert_eq!(
+        HilbertIndices::<1, i8>::from_bounds([[-128, 127]]).unwrap_err(),
+        IndexError::Overflow
+    );
+    assert_eq!(
+        HilbertIndices::<1, i8>::from_bounds([[-128, -64]])
+            .unwrap()
+            .last(),
+        Some([-65])
+    );
+}
+
+#[test]
+fn test_skip_outside_distances() {
+    // The 5 x 3 array is covered by the curve of order 3. Besides single cells, whole sub-cubes of 4 and
+    // 16 distances (e.g. 16..32 and 32..48) lie outside of the array and are skipped at once.
+    let distances: Vec<usize> = HilbertIndices::new([5usize, 3])
+        .unwrap()
+        .map(|cart| cart_to_hilbert(&cart, 3).unwrap())
+        .collect();
+    assert_eq!(
+        distances,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 13, 14, 54, 57, 58]
+    );
+
+    // Covered by the curve of order 14 with 2^28 cells, of which only 2^15 lie inside of the array
+    let cartiter = HilbertIndices::<2, u64>::new([2, 1 << 14]).unwrap();
+    assert_eq!(cartiter.len(), 1 << 15);
+    assert_eq!(cartiter.filter(|[row, _]| *row == 1).count(), 1 << 14);
+}
+
+proptest! {
+    #[test]
+    fn prop_round_trip(order in 0u32..20, seed in any::<[usize; 3]>()) {
+        let cart = seed.map(|index| index % (1 << order));
+        let distance = cart_to_hilbert(&cart, order).unwrap();
+        prop_assert!(distance < 1 << (3 * order));
+        prop_assert_eq!(cart_to_hilbert_unchecked(&cart, order), distance);
+        prop_assert_eq!(hilbert_to_cart(distance, order), Ok(cart));
+        prop_assert_eq!(hilbert_to_cart_unchecked(distance, order), cart);
+    }
+
+    #[test]
+    fn prop_iterator_sorted_by_distance(
+        start in prop::array::uniform3(0usize..10),
+        extent in prop::array::uniform3(1usize..10),
+    ) {
+        let bounds = [0, 1, 2].map(|axis| [start[axis], start[axis] + extent[axis]]);
+        let order = extent.iter().map(|size| size.next_power_of_two().trailing_zeros()).max().unwrap();
+        let mut reference: Vec<[usize; 3]> = CartesianIndices::from_bounds(bounds).unwrap().collect();
+        reference.sort_by_key(|cart| {
+            let relative = [0, 1, 2].map(|axis| cart[axis] - start[axis]);
+            cart_to_hilbert(&relative, order).unwrap()
+        });
+
+        let cartiter = HilbertIndices::from_bounds(bounds).unwrap();
+        prop_assert_eq!(cartiter.len(), reference.len());
+        prop_assert_eq!(cartiter.collect::<Vec<_>>(), reference);
+    }
+}
@@ -0,0 +1,138 @@
+use cart_lin::{
+    CartesianIndices, ColumnMajor, IndexError, IndexInt, Order, PermutedLayout, cart_to_lin,
+    cart_to_lin_unchecked, lin_to_cart, lin_to_cart_dyn, validate_shape,
+};
+
+/// Compares the conversion results for the index type `T` with those for `usize`.
+fn compare_with_usize<T: IndexInt>(dim_size: [usize; 3]) {
+    let dim_size_t = dim_size.map(|d| T::try_from(d).ok().unwrap());
+    let len = validate_shape(&dim_size).unwrap();
+    assert_eq!(
+        validate_shape(&dim_size_t)
+            .ok()
+            .and_then(|l| l.try_into().ok()),
+        Some(len)
+    );
+
+    let layout = PermutedLayout::new([2, 0, 1]).unwrap();
+    for (lin, cart) in CartesianIndices::new(dim_size_t).enumerate() {
+        let lin_t = T::try_from(lin).ok().unwrap();
+        let cart_usize = cart.map(|c| c.try_into().ok().unwrap());
+        assert_eq!(lin_to_cart(lin, &dim_size).unwrap(), cart_usize);
+
+        assert_eq!(cart_to_lin(&cart, &dim_size_t), Ok(lin_t));
+        assert_eq!(lin_to_cart(lin_t, &dim_size_t), Ok(cart));
+
+        let lin_column_major = ColumnMajor::cart_to_lin(&cart_usize, &dim_size).unwrap();
+        let lin_column_major_t = ColumnMajor::cart_to_lin(&cart, &dim_size_t).unwrap();
+        assert_eq!(lin_column_major_t.try_into().ok(), Some(lin_column_major));
+
+        let lin_permuted = layout.cart_to_lin(&cart_usize, &dim_size).unwrap();
+        let lin_permuted_t = layout.cart_to_lin(&cart, &dim_size_t).unwrap();
+        assert_eq!(lin_permuted_t.try_into().ok(), Some(lin_permuted));
+        assert_eq!(layout.lin_to_cart(lin_permuted_t, &dim_size_t), Ok(cart));
+    }
+
+    let len_t = T::try_from(len).ok().unwrap();
+    assert_eq!(
+        lin_to_cart(len_t, &dim_size_t),
+        Err(IndexError::LinearOutOfRange {
+            index: len_t,
+            len: len_t
+        })
+    );
+}
+
+#[test]
+fn test_all_types() {
+    for dim_size in [[1, 1, 1], [2, 3, 4], [5, 1, 7], [3, 3, 3]] {
+        compare_with_usize::<u8>(dim_size);
+        compare_with_usize::<u16>(dim_size);
+        compare_with_usize::<u32>(dim_size);
+        compare_with_usize::<u64>(dim_size);
+        compare_with_usize::<u128>(dim_size);
+        compare_with_usize::<i8>(dim_size);
+        compare_with_usize::<i16>(dim_size);
+        compare_with_usize::<i32>(dim_size);
+        compare_with_usize::<i64>(dim_size);
+        compare_with_usize::<i128>(dim_size);
+        compare_with_usize::<isize>(dim_size);
+    }
+}
+
+#[test]
+fn test_overflow_per_width() {
+    assert_eq!(validate_shape::<u8>(&[15, 17]), Ok(255));
+    assert_eq!(validate_shape::<u8>(&[16, 16]), Err(IndexError::Overflow));
+    assert_eq!(validate_shape::<i8>(&[127, 1]), Ok(127));
+    assert_eq!(validate_shape::<i8>(&[8, 16]), Err(IndexError::Overflow));
+    assert_eq!(
+        validate_shape::<u32>(&[1 << 16, 1 << 16]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        validate_shape::<u64>(&[1 << 32, 1 << 32]),
+        Err(IndexError::Overflow)
+    );
+
+    // Linear indices beyond 2^64 (independent of the target's pointer width)
+    let dim_size: [u128; 3] = [1 << 40, 1 << 40, 1 << 40];
+    let last = [(1 << 40) - 1, (1 << 40) - 1, (1 << 40) - 1];
+    assert_eq!(cart_to_lin(&last, &dim_size), Ok((1 << 120) - 1));
+    assert_eq!(lin_to_cart((1 << 120) - 1, &dim_size), Ok(last));
+    assert_eq!(
+        cart_to_lin(&[0, 0, 0, 0], &[1u128 << 40, 1 << 40, 1 << 40, 1 << 40]),
+        Err(IndexError::Overflow)
+    );
+}
+
+#[test]
+fn test_signed() {
+    assert_eq!(
+        cart_to_lin::<i64>(&[1, -1], &[2, 3]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: -1,
+            bound: 3
+        })
+    );
+    assert_eq!(
+        lin_to_cart::<i32, 2>(-1, &[2, 3]),
+        Err(IndexError::LinearOutOfRange { index: -1, len: 6 })
+    );
+    assert_eq!(
+        lin_to_cart::<i32, 2>(0, &[-2, -3]),
+        Err(IndexError::NegativeDimension { axis: 0, size: -2 })
+    );
+    let mut indices = [0i16; 2];
+    assert_eq!(
+        lin_to_cart_dyn::<i16>(0, &[2, -3], &mut indices),
+        Err(IndexError::NegativeDimension { axis: 1, size: -3 })
+    );
+
+    // Same (nonsensical) value as for the unsigned types
+    assert_eq!(cart_to_lin_unchecked::<i64>(&[1, 3], &[2, 3]), 6);
+}
+
+#[test]
+fn test_cartesian_indices() {
+    let indices: Vec<[u32; 2]> = CartesianIndices::new([2, 2]).collect();
+    assert_eq!(indices, [[0, 0], [0, 1], [1, 0], [1, 1]]);
+
+    // Bounds may be negative for signed types
+    let indices: Vec<[i64; 2]> = CartesianIndices::from_bounds([[-1, 1], [5, 7]])
+        .unwrap()
+        .collect();
+    assert_eq!(indices, [[-1, 5], [-1, 6], [0, 5], [0, 6]]);
+
+    // The extent of an axis does not fit into an i8
+    assert_eq!(
+        CartesianIndices::<1, i8>::from_bounds([[-100, 100]]).unwrap_err(),
+        IndexError::Overflow
+    );
+    assert_eq!(
+        CartesianIndices::<2, u8>::from_bounds([[0, 16], [0, 16]]).unwrap_err(),
+        IndexError::Overflow
+    );
+    assert_eq!(CartesianIndices::<2, u8>::new([15, 17]).count(), 255);
+}
@@ -0,0 +1,214 @@
+use cart_lin::{
+    CartesianIndices, IndexError, MortonIndices, cart_to_morton, cart_to_morton_unchecked,
+    morton_to_cart, morton_to_cart_unchecked,
+};
+use proptest::prelude::*;
+
+/// Reference implementation: interleaves the bits one code bit at a time.
+fn reference_morton(indices: &[usize], dim_size: &[usize]) -> usize {
+    let bits: Vec<u32> = dim_size
+        .iter()
+        .map(|size| usize::BITS - size.saturating_sub(1).leading_zeros())
+        .collect();
+    let mut code = 0;
+    let mut position = 0;
+    for level in 0..bits.iter().copied().max().unwrap_or(0) {
+        for axis in (0..dim_size.len()).rev() {
+            if bits[axis] > level {
+                code |= ((indices[axis] >> level) & 1) << position;
+                position += 1;
+            }
+        }
+    }
+    code
+}
+
+#[test]
+fn test_square() {
+    let dim_size = [4usize, 4];
+    let expected = [[0, 1, 4, 5], [2, 3, 6, 7], [8, 9, 12, 13], [10, 11, 14, 15]];
+    for [i, j] in CartesianIndices::new(dim_size) {
+        assert_eq!(cart_to_morton(&[i, j], &dim_size), Ok(expected[i][j]));
+        assert_eq!(morton_to_cart(expected[i][j], &dim_size), Ok([i, j]));
+    }
+}
+
+#[test]
+fn test_non_power_of_two() {
+    // The second axis contributes one bit more than the first one
+    let dim_size = [3usize, 5];
+    assert_eq!(cart_to_morton(&[2, 4], &dim_size), Ok(24));
+    assert_eq!(morton_to_cart(24, &dim_size), Ok([2, 4]));
+
+    // Unused codes
+    assert_eq!(
+        morton_to_cart(10, &dim_size),
+        Err(IndexError::OutOfBounds {
+            axis: 0,
+            index: 3,
+            bound: 3
+        })
+    );
+    assert_eq!(morton_to_cart_unchecked(10, &dim_size), [3, 0]);
+
+    // Axes of size one do not contribute any bits
+    assert_eq!(cart_to_morton(&[0, 3, 0], &[1, 4, 1]), Ok(3));
+    assert_eq!(morton_to_cart(3, &[1usize, 4, 1]), Ok([0, 3, 0]));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        cart_to_morton(&[0, 4], &[4usize, 4]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 4,
+            bound: 4
+        })
+    );
+    assert!(cart_to_morton(&[0], &[4usize, 4]).is_err());
+    assert_eq!(
+        morton_to_cart(16, &[4usize, 4]),
+        Err(IndexError::LinearOutOfRange { index: 16, len: 16 })
+    );
+    assert_eq!(
+        morton_to_cart::<i32, 2>(-1, &[4, 4]),
+        Err(IndexError::LinearOutOfRange { index: -1, len: 16 })
+    );
+    assert_eq!(
+        morton_to_cart::<i16, 2>(0, &[-1, 4]),
+        Err(IndexError::NegativeDimension { axis: 0, size: -1 })
+    );
+
+    // The Morton codes of a shape require more bits than its linear indices
+    assert_eq!(
+        cart_to_morton::<u8>(&[0, 0], &[16, 16]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        cart_to_morton::<u8>(&[0, 0], &[16, 15]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        cart_to_morton::<i8>(&[7, 15], &[8, 16]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(cart_to_morton::<i8>(&[7, 7], &[8, 8]), Ok(63));
+    assert_eq!(
+        morton_to_cart::<u8, 2>(255, &[16, 16]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        MortonIndices::<2, u8>::new([16, 16]).unwrap_err(),
+        IndexError::Overflow
+    );
+}
+
+#[test]
+fn test_index_types() {
+    assert_eq!(cart_to_morton::<u8>(&[15, 7], &[16, 8]), Ok(127));
+    assert_eq!(morton_to_cart::<u8, 2>(127, &[16, 8]), Ok([15, 7]));
+    assert_eq!(cart_to_morton::<i64>(&[3, 2, 1], &[4, 4, 4]), Ok(53));
+
+    let large = 1u128 << 60;
+    assert_eq!(
+        cart_to_morton::<u128>(&[large - 1, 0], &[large, large]),
+        Ok(0xaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaaa)
+    );
+    assert_eq!(
+        morton_to_cart::<u128, 2>(u128::MAX >> 8, &[large, large]),
+        Ok([large - 1, large - 1])
+    );
+
+    // The unchecked functions discard bits which do not fit into the index type
+    assert_eq!(
+        cart_to_morton_unchecked::<u8>(&[255, 255], &[255, 255]),
+        255
+    );
+    assert_eq!(
+        morton_to_cart_unchecked::<u8, 2>(255, &[255, 255]),
+        [15, 15]
+    );
+}
+
+#[test]
+fn test_iterator() {
+    let mut cartiter = MortonIndices::new([2usize, 3]).unwrap();
+    assert_eq!(cartiter.len(), 6);
+    assert_eq!(
+        cartiter.by_ref().collect::<Vec<_>>(),
+        [[0, 0], [0, 1], [1, 0], [1, 1], [0, 2], [1, 2]]
+    );
+    assert_eq!(cartiter.next(), None);
+
+    assert_eq!(MortonIndices::new([3usize, 0, 2]).unwrap().count(), 0);
+    assert_eq!(
+        MortonIndices::<0>::new([]).unwrap().collect::<Vec<_>>(),
+        [[]]
+    );
+
+    // Largest shape whose codes fit into the index type
+    let cartiter = MortonIndices::<2, u8>::new([16, 5]).unwrap();
+    assert_eq!(cartiter.len(), 80);
+    assert_eq!(cartiter.last(), Some([15, 4]));
+}
+
+#[test]
+fn test_skip_out_of_bounds_codes() {
+    // The first axis uses 3 bits and the second axis 2 bits. Single out-of-bounds codes (e.g. 5 for [0, 3])
+    // as well as blocks of codes sharing an out-of-bounds prefix (18..20 and 22..24 for the first index 5,
+    // 24..32 for the first indices 6 and 7) are skipped.
+    let dim_size = [5usize, 3];
+    let codes: Vec<usize> = MortonIndices::new(dim_size)
+        .unwrap()
+        .map(|cart| cart_to_morton(&cart, &dim_size).unwrap())
+        .collect();
+    assert_eq!(codes, [0, 1, 2, 3, 4, 6, 8, 9, 10, 11, 12, 14, 16, 17, 20]);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_reference(dim_size in prop::array::uniform3(1usize..40), seed in any::<[usize; 3]>()) {
+        let indices = [
+            seed[0] % dim_size[0],
+            seed[1] % dim_size[1],
+            seed[2] % dim_size[2],
+        ];
+        let code = reference_morton(&indices, &dim_size);
+        prop_assert_eq!(cart_to_morton(&indices, &dim_size), Ok(code));
+        prop_assert_eq!(cart_to_morton_unchecked(&indices, &dim_size), code);
+        prop_assert_eq!(morton_to_cart(code, &dim_size), Ok(indices));
+        prop_assert_eq!(morton_to_cart_unchecked(code, &dim_size), indices);
+    }
+
+    #[test]
+    fn prop_iterator_sorted_by_code(dim_size in prop::array::uniform3(0usize..12)) {
+        let mut reference: Vec<[usize; 3]> = CartesianIndices::new(dim_size).collect();
+        reference.sort_by_key(|cart| reference_morton(cart, &dim_size));
+
+        let cartiter = MortonIndices::new(dim_size).unwrap();
+        prop_assert_eq!(cartiter.len(), reference.len());
+        prop_assert_eq!(cartiter.collect::<Vec<_>>(), reference);
+    }
+
+    #[test]
+    fn prop_unused_codes(dim_size in prop::array::uniform2(1usize..20), code in 0usize..1024) {
+        let cart = morton_to_cart_unchecked(code, &dim_size);
+        let len: usize = dim_size.iter().map(|size| size.next_power_of_two()).product();
+        if code >= len {
+            prop_assert_eq!(
+                morton_to_cart(code, &dim_size),
+                Err(IndexError::LinearOutOfRange { index: code, len })
+            );
+        } else if cart[0] < dim_size[0] && cart[1] < dim_size[1] {
+            prop_assert_eq!(morton_to_cart(code, &dim_size), Ok(cart));
+            prop_assert_eq!(cart_to_morton(&cart, &dim_size), Ok(code));
+        } else {
+            let is_out_of_bounds = matches!(
+                morton_to_cart(code, &dim_size),
+                Err(IndexError::OutOfBounds { .. })
+            );
+            prop_assert!(is_out_of_bounds);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Column-major indexing
 
-use cart_lin::{cart_to_lin, lin_to_cart};
+use cart_lin::{ColumnMajor, Order, cart_to_lin, lin_to_cart};
 use nalgebra::{DMatrix, DVector, Dim, Matrix, Matrix2x3, RawStorage, Vector3};
 use std::ptr::addr_of;
 
@@ -22,6 +22,7 @@ fn lin_to_cart_nalgebra<R: Dim, C: Dim, S: RawStorage<f64, R, C>>(m: Matrix<f64,
     Convert a linear to a cartesian index and test that neighboring indices really are neighboring in memory
     by comparing the memory address
      */
+    #[allow(clippy::useless_conversion)]
     let indices: Vec<usize> = (0..nrows * ncols).into_iter().collect();
     for win in indices.as_slice().windows(2) {
         let c0 = lin_to_cart(win[0], &[nrows, ncols]).unwrap();
@@ -66,3 +67,30 @@ fn cart_to_lin_nalgebra<R: Dim, C: Dim, S: RawStorage<f64, R, C>>(m: Matrix<f64,
         assert_eq!(offset, 1);
     }
 }
+
+#[test]
+fn test_column_major() {
+    column_major_nalgebra(Matrix2x3::from_element(1.0));
+    column_major_nalgebra(DMatrix::from_element(5, 10, 1.0));
+    column_major_nalgebra(DMatrix::from_element(1, 10, 1.0));
+    column_major_nalgebra(DMatrix::from_element(10, 1, 1.0));
+    column_major_nalgebra(Vector3::from_element(1.0));
+    column_major_nalgebra(DVector::from_element(10, 1.0));
+}
+
+fn column_major_nalgebra<R: Dim, C: Dim, S: RawStorage<f64, R, C>>(m: Matrix<f64, R, C, S>) {
+    let dim_size = [m.nrows(), m.ncols()];
+    let data = unsafe { m.data.as_slice_unchecked() };
+
+    // The linear index of an element must be its position within the underlying buffer
+    for row in 0..m.nrows() {
+        for col in 0..m.ncols() {
+            let lin = ColumnMajor::cart_to_lin(&[row, col], &dim_size).unwrap();
+            assert!(std::ptr::eq(&m[(row, col)], &data[lin]));
+            assert_eq!(
+                ColumnMajor::lin_to_cart(lin, &dim_size).unwrap(),
+                [row, col]
+            );
+        }
+    }
+}
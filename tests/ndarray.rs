@@ -1,7 +1,7 @@
 //! Column-major indexing
 
-use cart_lin::lin_to_cart;
-use ndarray::{Array2, Array3, Array4, Axis};
+use cart_lin::{ColumnMajor, StridedLayout, lin_to_cart};
+use ndarray::{Array2, Array3, Array4, Axis, s};
 use std::ptr::addr_of;
 
 #[test]
@@ -14,6 +14,7 @@ fn test_lin_to_cart_2d() {
     Convert a linear to a cartesian index and test that neighboring indices really are neighboring in memory
     by comparing the memory address
      */
+    #[allow(clippy::useless_conversion)]
     let indices: Vec<usize> = (0..nrows * ncols).into_iter().collect();
     for (win, (cartidx, _)) in indices.as_slice().windows(2).zip(m.indexed_iter()) {
         let c0 = lin_to_cart(win[0], &[nrows, ncols]).unwrap();
@@ -40,6 +41,7 @@ fn test_lin_to_cart_3d() {
     Convert a linear to a cartesian index and test that neighboring indices really are neighboring in memory
     by comparing the memory address
      */
+    #[allow(clippy::useless_conversion)]
     let indices: Vec<usize> = (0..n0 * n1 * n2).into_iter().collect();
     for (win, (cartidx, _)) in indices.as_slice().windows(2).zip(m.indexed_iter()) {
         let c0 = lin_to_cart(win[0], &[n0, n1, n2]).unwrap();
@@ -67,6 +69,7 @@ fn test_lin_to_cart_4d() {
     Convert a linear to a cartesian index and test that neighboring indices really are neighboring in memory
     by comparing the memory address
      */
+    #[allow(clippy::useless_conversion)]
     let indices: Vec<usize> = (0..n0 * n1 * n2 * n3).into_iter().collect();
     for (win, (cartidx, _)) in indices.as_slice().windows(2).zip(m.indexed_iter()) {
         let c0 = lin_to_cart(win[0], &[n0, n1, n2, n3]).unwrap();
@@ -126,3 +129,28 @@ fn test_cart_to_lin_4d() {
         assert_eq!(cartidx.3, cartidx_calc[3]);
     }
 }
+
+#[test]
+fn test_strided_views() {
+    let m = Array3::<f64>::zeros((4, 5, 6));
+    let base = m.as_ptr();
+
+    let view = m.slice(s![1..;2, ..;-1, 1..5;3]);
+    let offset = unsafe { view.as_ptr().offset_from(base) } as usize;
+    let dim_size: [usize; 3] = view.shape().try_into().unwrap();
+    let strides: [isize; 3] = view.strides().try_into().unwrap();
+    let layout = StridedLayout::new(offset, dim_size, strides);
+
+    for (cartidx, elem) in view.indexed_iter() {
+        let cart = [cartidx.0, cartidx.1, cartidx.2];
+        let elem_offset = unsafe { (elem as *const f64).offset_from(base) } as usize;
+        assert_eq!(layout.cart_to_offset(&cart).unwrap(), elem_offset);
+        assert_eq!(layout.offset_to_cart(elem_offset).unwrap(), cart);
+    }
+
+    let transposed = m.view().reversed_axes();
+    let dim_size: [usize; 3] = transposed.shape().try_into().unwrap();
+    let strides: [isize; 3] = transposed.strides().try_into().unwrap();
+    let layout = StridedLayout::new(0, dim_size, strides);
+    assert_eq!(layout, StridedLayout::from_order::<ColumnMajor>([6, 5, 4]));
+}
@@ -0,0 +1,177 @@
+use cart_lin::{
+    CartesianIndices, ColumnMajor, FastShape, IndexError, Order, PermutedLayout, Shape,
+    StridedLayout, cart_to_lin, lin_to_cart, lin_to_cart_dyn, validate_shape,
+};
+#[cfg(feature = "alloc")]
+use cart_lin::{CartesianIndicesDyn, ShapeDyn};
+
+#[test]
+fn test_validate_shape() {
+    assert_eq!(validate_shape(&[]), Ok(1));
+    assert_eq!(validate_shape(&[usize::MAX]), Ok(usize::MAX));
+    assert_eq!(
+        validate_shape(&[1 << 32, (1 << 32) - 1]),
+        Ok(usize::MAX - ((1 << 32) - 1))
+    );
+    assert_eq!(
+        validate_shape(&[1usize << 32, 1 << 32]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        validate_shape(&[usize::MAX, 2, 0]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(validate_shape(&[usize::MAX, 1, 0]), Ok(0));
+}
+
+#[test]
+fn test_conversion_overflow() {
+    // Without the overflow check, the number of elements would wrap around to usize::MAX - 1,
+    // which would make the index below appear to be in bounds
+    let dim_size = [usize::MAX, 2];
+    assert_eq!(cart_to_lin(&[1, 1], &dim_size), Err(IndexError::Overflow));
+    assert_eq!(lin_to_cart(3, &dim_size), Err(IndexError::Overflow));
+    let mut buffer = [0, 0];
+    assert_eq!(
+        lin_to_cart_dyn(3, &dim_size, &mut buffer),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(buffer, [0, 0]);
+
+    assert_eq!(
+        ColumnMajor::cart_to_lin(&[1, 1], &dim_size),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        ColumnMajor::lin_to_cart(3, &dim_size),
+        Err(IndexError::Overflow)
+    );
+
+    let layout = PermutedLayout::new([1, 0]).unwrap();
+    assert_eq!(
+        layout.cart_to_lin(&[1, 1], &dim_size),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(layout.lin_to_cart(3, &dim_size), Err(IndexError::Overflow));
+
+    // Out-of-bounds errors take precedence
+    assert_eq!(
+        cart_to_lin(&[1, 2], &dim_size),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 2,
+            bound: 2
+        })
+    );
+}
+
+#[test]
+fn test_conversion_large_shape() {
+    // The largest shape whose number of elements still fits into an usize
+    let dim_size: [usize; 2] = [1 << 32, (1 << 32) - 1];
+    let last = [(1 << 32) - 1, (1 << 32) - 2];
+    let len = validate_shape(&dim_size).unwrap();
+
+    assert_eq!(cart_to_lin(&last, &dim_size), Ok(len - 1));
+    assert_eq!(lin_to_cart(len - 1, &dim_size), Ok(last));
+    assert_eq!(
+        lin_to_cart(len, &dim_size),
+        Err(IndexError::LinearOutOfRange { index: len, len })
+    );
+    assert_eq!(ColumnMajor::cart_to_lin(&last, &dim_size), Ok(len - 1));
+    assert_eq!(ColumnMajor::lin_to_cart(len - 1, &dim_size), Ok(last));
+}
+
+#[test]
+fn test_cartesian_indices_overflow() {
+    assert_eq!(
+        CartesianIndices::from_bounds([[1, usize::MAX], [0, 3]]).unwrap_err(),
+        IndexError::Overflow
+    );
+
+    let mut cartiter = CartesianIndices::from_bounds([[0usize, 1 << 32], [1, 1 << 32]]).unwrap();
+    assert_eq!(cartiter.next(), Some([0, 1]));
+}
+
+#[test]
+fn test_cartesian_indices_try_new() {
+    assert_eq!(
+        CartesianIndices::try_new([usize::MAX, 2]).unwrap_err(),
+        IndexError::Overflow
+    );
+    assert_eq!(
+        CartesianIndices::<3, i64>::try_new([2, -1, 0]).unwrap_err(),
+        IndexError::NegativeDimension { axis: 1, size: -1 }
+    );
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        CartesianIndicesDyn::try_new(&[usize::MAX, 2]).unwrap_err(),
+        IndexError::Overflow
+    );
+
+    // Shapes without elements may have sizes whose product overflows
+    assert_eq!(CartesianIndices::try_new([0, usize::MAX]).unwrap().len(), 0);
+    let mut cartiter = CartesianIndices::try_new([1usize << 32, (1 << 32) - 1]).unwrap();
+    assert_eq!(cartiter.next(), Some([0, 0]));
+}
+
+#[test]
+#[should_panic]
+fn test_cartesian_indices_new_overflow() {
+    CartesianIndices::new([usize::MAX, 2]);
+}
+
+#[test]
+fn test_shape_overflow() {
+    assert_eq!(Shape::try_new([usize::MAX, 2]), Err(IndexError::Overflow));
+    assert_eq!(
+        Shape::try_from_order::<ColumnMajor>([2, 0, usize::MAX]),
+        Err(IndexError::Overflow)
+    );
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        ShapeDyn::try_new(&[usize::MAX, 2]),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        FastShape::try_new([usize::MAX, 2]),
+        Err(IndexError::Overflow)
+    );
+
+    let shape = Shape::try_new([1 << 32, (1 << 32) - 1]).unwrap();
+    assert_eq!(shape.strides(), &[(1 << 32) - 1, 1]);
+    assert_eq!(shape.len(), usize::MAX - ((1 << 32) - 1));
+
+    let shape = Shape::try_new([0, usize::MAX]).unwrap();
+    assert!(shape.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_shape_new_overflow() {
+    Shape::new([usize::MAX, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_shape_dyn_new_overflow() {
+    ShapeDyn::new(&[2, usize::MAX]);
+}
+
+#[test]
+#[should_panic]
+fn test_fast_shape_new_overflow() {
+    FastShape::new([usize::MAX, usize::MAX]);
+}
+
+#[test]
+fn test_strided_layout_overflow() {
+    let layout = StridedLayout::new(usize::MAX, [2, 2], [isize::MAX, isize::MAX]);
+    assert_eq!(layout.cart_to_offset(&[0, 0]), Ok(usize::MAX));
+    assert_eq!(layout.cart_to_offset(&[1, 0]), Err(IndexError::Overflow));
+
+    // Negative strides may bring the offset back into range
+    let layout = StridedLayout::new(usize::MAX, [2, 2], [isize::MAX, isize::MIN]);
+    assert_eq!(layout.cart_to_offset(&[1, 1]), Ok(usize::MAX - 1));
+}
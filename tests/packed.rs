@@ -0,0 +1,194 @@
+use cart_lin::{
+    CartesianIndices, ColumnMajor, IndexError, Order, PackedTriangular, RowMajor, Uplo,
+};
+
+fn layout<O: Order>(n: usize, uplo: Uplo) -> PackedTriangular {
+    PackedTriangular::new(n, uplo).unwrap().order::<O>()
+}
+
+/// All combinations of stored triangle and memory order.
+fn layouts(n: usize) -> [PackedTriangular; 4] {
+    [
+        layout::<RowMajor>(n, Uplo::Upper),
+        layout::<ColumnMajor>(n, Uplo::Upper),
+        layout::<RowMajor>(n, Uplo::Lower),
+        layout::<ColumnMajor>(n, Uplo::Lower),
+    ]
+}
+
+#[test]
+fn test_uplo_order_combinations() {
+    // Linear index of each element of a 3 x 3 matrix, in the same order as `layouts`
+    let expected = [
+        [
+            [Some(0), Some(1), Some(2)],
+            [None, Some(3), Some(4)],
+            [None, None, Some(5)],
+        ],
+        [
+            [Some(0), Some(1), Some(3)],
+            [None, Some(2), Some(4)],
+            [None, None, Some(5)],
+        ],
+        [
+            [Some(0), None, None],
+            [Some(1), Some(2), None],
+            [Some(3), Some(4), Some(5)],
+        ],
+        [
+            [Some(0), None, None],
+            [Some(1), Some(3), None],
+            [Some(2), Some(4), Some(5)],
+        ],
+    ];
+    for (layout, expected) in layouts(3).into_iter().zip(expected) {
+        let mut stored = [[0, 0]; 6];
+        for [i, j] in CartesianIndices::new([3, 3]) {
+            match expected[i][j] {
+                Some(lin) => {
+                    assert_eq!(layout.cart_to_lin(&[i, j]), Ok(lin), "{layout:?}");
+                    assert_eq!(layout.cart_to_lin_unchecked(&[i, j]), lin);
+                    assert_eq!(layout.lin_to_cart(lin), Ok([i, j]));
+                    assert_eq!(layout.lin_to_cart_unchecked(lin), [i, j]);
+                    stored[lin] = [i, j];
+                }
+                None => assert!(layout.cart_to_lin(&[i, j]).is_err()),
+            }
+        }
+        assert_eq!(layout.indices().collect::<Vec<_>>(), stored);
+        assert!(layout.indices().rev().eq(stored.into_iter().rev()));
+    }
+}
+
+#[test]
+fn test_indices_in_memory_order() {
+    // Every combination steps through the rows of a different row-major equivalent, so check each step
+    // and each jump of the iterator against the conversion functions
+    for n in 0..8 {
+        for layout in layouts(n) {
+            let stored: Vec<_> = (0..layout.len())
+                .map(|lin| layout.lin_to_cart(lin).unwrap())
+                .collect();
+            assert_eq!(layout.indices().collect::<Vec<_>>(), stored);
+            assert!(layout.indices().rev().eq(stored.iter().copied().rev()));
+            for k in 0..=stored.len() {
+                assert_eq!(layout.indices().nth(k), stored.get(k).copied());
+                assert_eq!(
+                    layout.indices().nth_back(k),
+                    stored.iter().rev().nth(k).copied()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lapack_column_major() {
+    // Column-major packed storage as documented by LAPACK (with 0-based indices):
+    // Upper: AP(i + j * (j + 1) / 2) = A(i, j) for i <= j
+    // Lower: AP(i + j * (2 * n - j - 1) / 2) = A(i, j) for i >= j
+    let n = 7usize;
+    let upper = layout::<ColumnMajor>(n, Uplo::Upper);
+    let lower = layout::<ColumnMajor>(n, Uplo::Lower);
+    for [i, j] in CartesianIndices::new([n, n]) {
+        if i <= j {
+            assert_eq!(upper.cart_to_lin(&[i, j]), Ok(i + j * (j + 1) / 2));
+        }
+        if i >= j {
+            assert_eq!(lower.cart_to_lin(&[i, j]), Ok(i + j * (2 * n - j - 1) / 2));
+        }
+    }
+}
+
+#[test]
+fn test_mirrored_lookups() {
+    for layout in layouts(5) {
+        for [i, j] in CartesianIndices::new([5, 5]) {
+            // Exactly one of (i, j) and (j, i) is looked up, which is the stored one
+            let stored = if layout.cart_to_lin(&[i, j]).is_ok() {
+                [i, j]
+            } else {
+                [j, i]
+            };
+            let lin = layout.cart_to_lin(&stored).unwrap();
+            assert_eq!(layout.cart_to_lin_symmetric(&[i, j]), Ok(lin));
+            assert_eq!(layout.cart_to_lin_symmetric(&[j, i]), Ok(lin));
+            assert_eq!(layout.cart_to_lin_symmetric_unchecked(&[i, j]), lin);
+            assert_eq!(layout.cart_to_lin_symmetric_unchecked(&[j, i]), lin);
+            assert_eq!(layout.lin_to_cart(lin), Ok(stored));
+        }
+    }
+
+    // Elements of the other triangle are mirrored, even though they are in bounds of the storage
+    let layout = layout::<RowMajor>(3, Uplo::Upper);
+    let matrix = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    assert_eq!(matrix[layout.cart_to_lin_symmetric(&[2, 0]).unwrap()], 3.0);
+    assert_eq!(matrix[layout.cart_to_lin_symmetric(&[2, 1]).unwrap()], 5.0);
+    assert_eq!(
+        layout.cart_to_lin_symmetric(&[3, 0]),
+        Err(IndexError::OutOfBounds {
+            axis: 0,
+            index: 3,
+            bound: 3
+        })
+    );
+}
+
+#[test]
+fn test_not_stored() {
+    for layout in layouts(5) {
+        for [i, j] in CartesianIndices::new([5, 5]) {
+            let stored = match layout.uplo() {
+                Uplo::Upper => i <= j,
+                Uplo::Lower => i >= j,
+            };
+            if !stored {
+                // The error reports the requested element, not the transposed one of column-major layouts
+                assert_eq!(
+                    layout.cart_to_lin(&[i, j]),
+                    Err(IndexError::NotStored { row: i, column: j })
+                );
+            }
+        }
+        // Out-of-bounds indices are reported first
+        let outside = match layout.uplo() {
+            Uplo::Upper => [5, 0],
+            Uplo::Lower => [0, 5],
+        };
+        assert!(matches!(
+            layout.cart_to_lin(&outside),
+            Err(IndexError::OutOfBounds { .. })
+        ));
+    }
+    assert_eq!(
+        IndexError::<usize>::NotStored { row: 2, column: 0 }.to_string(),
+        "element (2, 0) is not stored by the layout"
+    );
+}
+
+#[test]
+fn test_errors() {
+    let layout = PackedTriangular::new(3usize, Uplo::Upper).unwrap();
+    assert_eq!(
+        layout.lin_to_cart(6),
+        Err(IndexError::LinearOutOfRange { index: 6, len: 6 })
+    );
+    assert_eq!(
+        PackedTriangular::<i32>::new(-1, Uplo::Lower),
+        Err(IndexError::NegativeDimension { axis: 0, size: -1 })
+    );
+
+    // Largest matrix whose packed storage fits into an i8
+    assert_eq!(
+        PackedTriangular::<i8>::new(15, Uplo::Upper).unwrap().len(),
+        120
+    );
+    assert_eq!(
+        PackedTriangular::<i8>::new(16, Uplo::Upper),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        PackedTriangular::new(usize::MAX, Uplo::Lower),
+        Err(IndexError::Overflow)
+    );
+}
@@ -0,0 +1,145 @@
+use cart_lin::{
+    CartesianIndices, ColumnMajor, IndexError, PackedSymmetric, PackedTriangular, RowMajor, Uplo,
+};
+
+#[test]
+fn test_matches_packed_triangular() {
+    // A symmetric matrix is stored like the upper triangle in row-major order, or equivalently like the
+    // lower triangle in column-major order
+    let n = 9usize;
+    let symmetric = PackedSymmetric::<2>::new(n).unwrap();
+    let upper = PackedTriangular::new(n, Uplo::Upper)
+        .unwrap()
+        .order::<RowMajor>();
+    let lower = PackedTriangular::new(n, Uplo::Lower)
+        .unwrap()
+        .order::<ColumnMajor>();
+    assert_eq!(symmetric.len(), upper.len());
+    for cart in CartesianIndices::new([n; 2]) {
+        assert_eq!(
+            symmetric.cart_to_lin_symmetric(&cart),
+            upper.cart_to_lin_symmetric(&cart)
+        );
+        assert_eq!(
+            symmetric.cart_to_lin_symmetric(&cart),
+            lower.cart_to_lin_symmetric(&cart)
+        );
+    }
+    assert!(symmetric.indices().eq(upper.indices()));
+}
+
+#[test]
+fn test_mirrored_lookups() {
+    // All permutations of a cartesian index refer to the same element
+    let layout = PackedSymmetric::<3>::new(4usize).unwrap();
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    for cart in CartesianIndices::new([4usize; 3]) {
+        let lin = layout.cart_to_lin_symmetric(&cart).unwrap();
+        for permutation in permutations {
+            let permuted = permutation.map(|axis| cart[axis]);
+            assert_eq!(layout.cart_to_lin_symmetric(&permuted), Ok(lin));
+            assert_eq!(layout.cart_to_lin_symmetric_unchecked(&permuted), lin);
+        }
+    }
+}
+
+#[test]
+fn test_canonicalize() {
+    let layout = PackedSymmetric::<4>::new(5usize).unwrap();
+    for cart in CartesianIndices::new([5usize; 4]) {
+        let representative = layout.canonicalize(&cart);
+        assert!(representative.windows(2).all(|pair| pair[0] <= pair[1]));
+        let lin = layout.cart_to_lin(&representative).unwrap();
+        assert_eq!(layout.cart_to_lin_symmetric(&cart), Ok(lin));
+        assert_eq!(layout.cart_to_lin_symmetric_unchecked(&cart), lin);
+        assert_eq!(layout.lin_to_cart(lin), Ok(representative));
+    }
+}
+
+#[test]
+fn test_errors() {
+    let layout = PackedSymmetric::<3>::new(4usize).unwrap();
+    assert_eq!(
+        layout.cart_to_lin(&[0, 3, 2]),
+        Err(IndexError::NotSorted { axis: 2 })
+    );
+    assert_eq!(
+        layout.cart_to_lin_symmetric(&[0, 4, 2]),
+        Err(IndexError::OutOfBounds {
+            axis: 1,
+            index: 4,
+            bound: 4
+        })
+    );
+    assert_eq!(
+        layout.lin_to_cart(20),
+        Err(IndexError::LinearOutOfRange { index: 20, len: 20 })
+    );
+    assert_eq!(
+        PackedSymmetric::<3, i32>::new(-1),
+        Err(IndexError::NegativeDimension { axis: 0, size: -1 })
+    );
+    // Largest tensor whose packed storage fits into an i8
+    assert_eq!(PackedSymmetric::<3, i8>::new(8).unwrap().len(), 120);
+    assert_eq!(PackedSymmetric::<3, i8>::new(9), Err(IndexError::Overflow));
+    assert_eq!(
+        PackedSymmetric::<3>::new(usize::MAX),
+        Err(IndexError::Overflow)
+    );
+    assert_eq!(
+        IndexError::<usize>::NotSorted { axis: 2 }.to_string(),
+        "index of axis 2 is smaller than the index of the previous axis"
+    );
+
+    let empty = PackedSymmetric::<3>::new(0usize).unwrap();
+    assert!(empty.is_empty());
+    assert!(empty.lin_to_cart(0).is_err());
+}
+
+#[test]
+fn test_degenerate_ranks() {
+    // A tensor of rank zero is a scalar, even for n = 0
+    for n in [0usize, 7] {
+        let scalar = PackedSymmetric::<0>::new(n).unwrap();
+        assert_eq!(scalar.len(), 1);
+        assert_eq!(scalar.cart_to_lin(&[]), Ok(0));
+        assert_eq!(scalar.lin_to_cart(0), Ok([]));
+        assert_eq!(scalar.indices().collect::<Vec<_>>(), [[]]);
+    }
+
+    // A tensor of rank one is a vector, whose indices are trivially sorted
+    let vector = PackedSymmetric::<1>::new(5usize).unwrap();
+    assert_eq!(vector.len(), 5);
+    for index in 0..5 {
+        assert_eq!(vector.cart_to_lin(&[index]), Ok(index));
+        assert_eq!(vector.lin_to_cart(index), Ok([index]));
+    }
+}
+
+#[test]
+fn test_indices_in_memory_order() {
+    // The iterator increments the sorted indices like an odometer, so check each step and each jump of the
+    // iterator against the conversion functions
+    for n in 0..6usize {
+        let layout = PackedSymmetric::<3>::new(n).unwrap();
+        let stored: Vec<_> = (0..layout.len())
+            .map(|lin| layout.lin_to_cart(lin).unwrap())
+            .collect();
+        assert_eq!(layout.indices().collect::<Vec<_>>(), stored);
+        assert!(layout.indices().rev().eq(stored.iter().copied().rev()));
+        for k in 0..=stored.len() {
+            assert_eq!(layout.indices().nth(k), stored.get(k).copied());
+            assert_eq!(
+                layout.indices().nth_back(k),
+                stored.iter().rev().nth(k).copied()
+            );
+        }
+    }
+}
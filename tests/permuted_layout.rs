@@ -0,0 +1,50 @@
+use cart_lin::{ColumnMajor, Order, PermutedLayout, RowMajor, cart_to_lin, lin_to_cart};
+
+#[test]
+fn test_permutation_validation() {
+    assert!(PermutedLayout::new([0]).is_ok());
+    assert!(PermutedLayout::new([1]).is_err());
+    assert!(PermutedLayout::new([3, 1, 0, 2]).is_ok());
+    assert!(PermutedLayout::new([3, 1, 1, 2]).is_err());
+    assert!(PermutedLayout::new([4, 1, 0, 2]).is_err());
+    assert!(PermutedLayout::<0>::new([]).is_ok());
+}
+
+#[test]
+fn test_row_and_column_major() {
+    let dim_size = [3, 2, 4];
+    let row_major = PermutedLayout::row_major();
+    let column_major = PermutedLayout::column_major();
+    for lin in 0..24 {
+        let cart = row_major.lin_to_cart(lin, &dim_size).unwrap();
+        assert_eq!(cart, lin_to_cart(lin, &dim_size).unwrap());
+        assert_eq!(cart, RowMajor::lin_to_cart(lin, &dim_size).unwrap());
+        assert_eq!(row_major.cart_to_lin(&cart, &dim_size).unwrap(), lin);
+
+        let cart = column_major.lin_to_cart(lin, &dim_size).unwrap();
+        assert_eq!(cart, ColumnMajor::lin_to_cart(lin, &dim_size).unwrap());
+        assert_eq!(column_major.cart_to_lin(&cart, &dim_size).unwrap(), lin);
+    }
+    assert!(row_major.lin_to_cart(24, &dim_size).is_err());
+    assert!(column_major.cart_to_lin(&[3, 0, 0], &dim_size).is_err());
+}
+
+#[test]
+fn test_nhwc() {
+    // Logical NCHW index into a tensor which is stored as NHWC
+    let nchw = [2, 3, 4, 5];
+    let nhwc = [2, 4, 5, 3];
+    let layout = PermutedLayout::new([0, 2, 3, 1]).unwrap();
+
+    for n in 0..2 {
+        for c in 0..3 {
+            for h in 0..4 {
+                for w in 0..5 {
+                    let lin = layout.cart_to_lin(&[n, c, h, w], &nchw).unwrap();
+                    assert_eq!(lin, cart_to_lin(&[n, h, w, c], &nhwc).unwrap());
+                    assert_eq!(layout.lin_to_cart(lin, &nchw).unwrap(), [n, c, h, w]);
+                }
+            }
+        }
+    }
+}
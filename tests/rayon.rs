@@ -0,0 +1,102 @@
+#![cfg(feature = "rayon")]
+
+use cart_lin::{CartesianIndices, CartesianIndicesBuilder, PermutedLayout};
+use proptest::prelude::*;
+use rayon::prelude::*;
+
+fn bounds_with_step_strategy() -> impl Strategy<Value = [[usize; 3]; 3]> {
+    [
+        (0usize..5, 1usize..9, 1usize..4),
+        (0usize..5, 1usize..9, 1usize..4),
+        (0usize..5, 1usize..9, 1usize..4),
+    ]
+    .prop_map(|axes| axes.map(|(start, len, step)| [start, start + len, step]))
+}
+
+#[test]
+fn test_matches_sequential() {
+    let cartiter = CartesianIndices::new([20, 30, 40]);
+    let sequential: Vec<_> = cartiter.clone().collect();
+    let parallel: Vec<_> = cartiter.clone().into_par_iter().with_max_len(7).collect();
+    assert_eq!(parallel, sequential);
+    assert_eq!(cartiter.clone().into_par_iter().len(), 24_000);
+
+    // Partially consumed iterators only yield the remaining elements
+    let mut cartiter = cartiter;
+    cartiter.nth(99);
+    cartiter.nth_back(99);
+    let parallel: Vec<_> = cartiter.clone().into_par_iter().collect();
+    assert_eq!(parallel, sequential[100..sequential.len() - 100]);
+}
+
+#[test]
+fn test_indexed_adapters() {
+    let dim_size = [7, 9, 11];
+    let enumerated: Vec<(usize, [usize; 3])> = CartesianIndices::new(dim_size)
+        .into_par_iter()
+        .enumerate()
+        .with_min_len(1)
+        .collect();
+    for (lin, cart) in enumerated {
+        assert_eq!(cart_lin::cart_to_lin(&cart, &dim_size), Ok(lin));
+    }
+
+    let reversed: Vec<_> = CartesianIndices::new(dim_size)
+        .into_par_iter()
+        .rev()
+        .skip(5)
+        .step_by(3)
+        .collect();
+    let expected: Vec<_> = CartesianIndices::new(dim_size)
+        .rev()
+        .skip(5)
+        .step_by(3)
+        .collect();
+    assert_eq!(reversed, expected);
+}
+
+#[test]
+fn test_empty_and_index_types() {
+    assert_eq!(CartesianIndices::new([3, 0, 2]).into_par_iter().count(), 0);
+    assert_eq!(
+        CartesianIndices::<0>::new([])
+            .into_par_iter()
+            .collect::<Vec<_>>(),
+        [[]]
+    );
+
+    let parallel: Vec<[i8; 2]> = CartesianIndices::from_bounds([[-3, 3], [-5, 5]])
+        .unwrap()
+        .into_par_iter()
+        .with_max_len(1)
+        .collect();
+    let sequential: Vec<[i8; 2]> = CartesianIndices::from_bounds([[-3, 3], [-5, 5]])
+        .unwrap()
+        .collect();
+    assert_eq!(parallel, sequential);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_sequential(
+        bounds in bounds_with_step_strategy(),
+        order in Just(vec![0usize, 1, 2]).prop_shuffle(),
+        reversed in any::<[bool; 3]>(),
+        max_len in 1usize..10,
+    ) {
+        let mut builder = CartesianIndicesBuilder::from_bounds_with_step(bounds)
+            .order(PermutedLayout::new([order[0], order[1], order[2]]).unwrap());
+        for (axis, reversed) in reversed.into_iter().enumerate() {
+            if reversed {
+                builder = builder.reverse(axis);
+            }
+        }
+        let cartiter = builder.build().unwrap();
+        let sequential: Vec<_> = cartiter.clone().collect();
+        let parallel: Vec<_> = cartiter.clone().into_par_iter().with_max_len(max_len).collect();
+        prop_assert_eq!(&parallel, &sequential);
+
+        let parallel_rev: Vec<_> = cartiter.into_par_iter().with_max_len(max_len).rev().collect();
+        prop_assert_eq!(parallel_rev, sequential.into_iter().rev().collect::<Vec<_>>());
+    }
+}
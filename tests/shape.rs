@@ -0,0 +1,68 @@
+use cart_lin::{ColumnMajor, Order, Shape, cart_to_lin, lin_to_cart};
+#[cfg(feature = "alloc")]
+use cart_lin::{ShapeDyn, lin_to_cart_dyn};
+
+#[test]
+fn test_shape_matches_free_functions() {
+    let dim_size = [2, 4, 7];
+    let shape = Shape::new(dim_size);
+    assert_eq!(shape.len(), 56);
+    assert!(!shape.is_empty());
+    for lin in 0..56 {
+        let cart = shape.to_cart(lin).unwrap();
+        assert_eq!(cart, lin_to_cart(lin, &dim_size).unwrap());
+        assert_eq!(shape.to_lin(&cart).unwrap(), lin);
+        assert_eq!(cart_to_lin(&cart, &dim_size).unwrap(), lin);
+    }
+    assert!(shape.to_cart(56).is_err());
+    assert!(shape.to_lin(&[0, 4, 0]).is_err());
+}
+
+#[test]
+fn test_shape_column_major() {
+    let dim_size = [3, 5, 2];
+    let shape = Shape::from_order::<ColumnMajor>(dim_size);
+    assert_eq!(shape.strides(), &[1, 3, 15]);
+    for lin in 0..30 {
+        let cart = shape.to_cart(lin).unwrap();
+        assert_eq!(cart, ColumnMajor::lin_to_cart(lin, &dim_size).unwrap());
+        assert_eq!(shape.to_lin(&cart).unwrap(), lin);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_shape_dyn() {
+    let dim_size = vec![3, 1, 4, 2];
+    let shape = ShapeDyn::new(&dim_size);
+    assert_eq!(shape.ndim(), 4);
+    assert_eq!(shape.len(), 24);
+
+    let mut expected = vec![0; 4];
+    let mut cart = vec![0; 4];
+    for lin in 0..24 {
+        assert!(lin_to_cart_dyn(lin, &dim_size, &mut expected).is_ok());
+        assert!(shape.to_cart(lin, &mut cart).is_ok());
+        assert_eq!(cart, expected);
+        assert_eq!(shape.to_lin(&cart).unwrap(), lin);
+    }
+    assert!(shape.to_cart(24, &mut cart).is_err());
+    assert!(shape.to_cart(0, &mut cart[..3]).is_err());
+    assert!(shape.to_lin(&[0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_empty_shape() {
+    let shape = Shape::new([3, 0, 2]);
+    assert!(shape.is_empty());
+    assert!(shape.to_cart(0).is_err());
+    assert!(shape.to_lin(&[0, 0, 0]).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_empty_shape_dyn() {
+    let shape = ShapeDyn::new(&[]);
+    assert_eq!(shape.len(), 1);
+    assert_eq!(shape.to_lin(&[]).unwrap(), 0);
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use cart_lin::{SnakeIndices, cart_to_lin};
+use proptest::prelude::*;
+
+/// Reference implementation: recursively appends the passes along the inner axes, reversing every
+/// other pass.
+fn reference_snake(dim_size: &[usize]) -> Vec<Vec<usize>> {
+    let Some((size, inner)) = dim_size.split_first() else {
+        return vec![vec![]];
+    };
+    let inner_passes = reference_snake(inner);
+    let mut res = Vec::new();
+    for index in 0..*size {
+        let mut pass: Vec<Vec<usize>> = inner_passes
+            .iter()
+            .map(|cart| [vec![index], cart.clone()].concat())
+            .collect();
+        if index % 2 == 1 {
+            pass.reverse();
+        }
+        res.extend(pass);
+    }
+    res
+}
+
+fn reference_items(dim_size: [usize; 3]) -> Vec<(usize, [usize; 3])> {
+    reference_snake(&dim_size)
+        .into_iter()
+        .map(|cart| {
+            let cart = [cart[0], cart[1], cart[2]];
+            (cart_to_lin(&cart, &dim_size).unwrap(), cart)
+        })
+        .collect()
+}
+
+#[test]
+fn test_single_steps() {
+    let dim_size = [3usize, 4, 5, 2];
+    let items: Vec<_> = SnakeIndices::new(dim_size).collect();
+    assert_eq!(items.len(), 120);
+    let mut visited = [false; 120];
+    for (lin, cart) in items.iter() {
+        assert_eq!(cart_to_lin(cart, &dim_size), Ok(*lin));
+        assert!(!visited[*lin]);
+        visited[*lin] = true;
+    }
+    for pair in items.windows(2) {
+        let step: usize = pair[0]
+            .1
+            .iter()
+            .zip(pair[1].1.iter())
+            .map(|(a, b)| a.abs_diff(*b))
+            .sum();
+        assert_eq!(step, 1);
+    }
+}
+
+#[test]
+fn test_index_types() {
+    let items: Vec<(i8, [i8; 2])> = SnakeIndices::new([3, 2]).collect();
+    assert_eq!(
+        items,
+        [
+            (0, [0, 0]),
+            (1, [0, 1]),
+            (3, [1, 1]),
+            (2, [1, 0]),
+            (4, [2, 0]),
+            (5, [2, 1])
+        ]
+    );
+
+    // Largest shape of u8
+    let mut cartiter = SnakeIndices::<2, u8>::new([15, 17]);
+    assert_eq!(cartiter.len(), 255);
+    assert_eq!(cartiter.next_back(), Some((254, [14, 16])));
+    assert_eq!(cartiter.nth(253), Some((253, [14, 15])));
+    assert_eq!(cartiter.next(), None);
+}
+
+#[test]
+fn test_edge_cases() {
+    // Axes of size zero at any position and axes of size one, followed by steps past the end
+    let cases = [
+        ([0usize, 3, 2], vec![]),
+        ([3, 0, 2], vec![]),
+        ([3, 2, 0], vec![]),
+        ([1, 1, 1], vec![(0, [0, 0, 0])]),
+        ([1, 2, 1], vec![(0, [0, 0, 0]), (1, [0, 1, 0])]),
+    ];
+    for (dim_size, expected) in cases {
+        let cartiter = SnakeIndices::new(dim_size);
+        assert_eq!(cartiter.clone().collect::<Vec<_>>(), expected);
+        assert_eq!(cartiter.clone().rev().count(), expected.len());
+        assert_eq!(cartiter.clone().nth(expected.len()), None);
+        assert_eq!(cartiter.clone().nth_back(expected.len()), None);
+    }
+
+    // A shape of rank zero has a single element
+    assert_eq!(SnakeIndices::<0>::new([]).collect::<Vec<_>>(), [(0, [])]);
+    assert_eq!(SnakeIndices::<0>::new([]).nth_back(1), None);
+
+    // Steps which do not fit into the index type
+    assert_eq!(SnakeIndices::<2, u8>::new([2, 3]).nth(usize::MAX), None);
+    assert_eq!(SnakeIndices::<2, u8>::new([2, 3]).nth_back(256), None);
+}
+
+#[test]
+#[should_panic]
+fn test_overflow() {
+    let _ = SnakeIndices::new([usize::MAX, 2]);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_reference(
+        dim_size in prop::array::uniform3(1usize..6),
+        steps in prop::collection::vec((any::<bool>(), 0usize..8), 0..40),
+    ) {
+        let reference = reference_items(dim_size);
+        common::check_random_access(SnakeIndices::new(dim_size), &reference, &steps)?;
+    }
+}
@@ -0,0 +1,75 @@
+use cart_lin::{ColumnMajor, Order, RowMajor, StridedLayout, cart_to_lin};
+
+#[test]
+fn test_contiguous() {
+    let dim_size = [3, 2, 4];
+    let row_major = StridedLayout::from_order::<RowMajor>(dim_size);
+    let column_major = StridedLayout::from_order::<ColumnMajor>(dim_size);
+    for i in 0..3 {
+        for j in 0..2 {
+            for k in 0..4 {
+                let cart = [i, j, k];
+                let lin = cart_to_lin(&cart, &dim_size).unwrap();
+                assert_eq!(row_major.cart_to_offset(&cart).unwrap(), lin);
+                assert_eq!(row_major.cart_to_offset_unchecked(&cart), lin);
+                assert_eq!(row_major.offset_to_cart(lin).unwrap(), cart);
+
+                let lin = ColumnMajor::cart_to_lin(&cart, &dim_size).unwrap();
+                assert_eq!(column_major.cart_to_offset(&cart).unwrap(), lin);
+                assert_eq!(column_major.offset_to_cart(lin).unwrap(), cart);
+            }
+        }
+    }
+    assert!(row_major.cart_to_offset(&[3, 0, 0]).is_err());
+    assert!(row_major.offset_to_cart(24).is_err());
+}
+
+#[test]
+fn test_negative_strides() {
+    // 3 x 4 row-major matrix with both axes reversed
+    let layout = StridedLayout::new(11, [3, 4], [-4, -1]);
+    for lin in 0..12 {
+        let cart = layout.offset_to_cart(lin).unwrap();
+        assert_eq!(cart, [2 - lin / 4, 3 - lin % 4]);
+        assert_eq!(layout.cart_to_offset(&cart).unwrap(), lin);
+    }
+    assert!(layout.offset_to_cart(12).is_err());
+
+    // Negative offsets are rejected
+    let layout = StridedLayout::new(1, [3], [-1]);
+    assert_eq!(layout.cart_to_offset(&[1]).unwrap(), 0);
+    assert!(layout.cart_to_offset(&[2]).is_err());
+}
+
+#[test]
+fn test_non_nested_strides() {
+    // Non-overlapping layout whose strides are not nested: {0, 2, 4} + {0, 3}
+    let layout = StridedLayout::new(0, [3, 2], [2, 3]);
+    let mut hit = Vec::new();
+    for i in 0..3 {
+        for j in 0..2 {
+            let offset = layout.cart_to_offset(&[i, j]).unwrap();
+            assert_eq!(layout.offset_to_cart(offset).unwrap(), [i, j]);
+            hit.push(offset);
+        }
+    }
+    for offset in 0..10 {
+        if !hit.contains(&offset) {
+            assert!(layout.offset_to_cart(offset).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_broadcasting() {
+    // A stride of zero repeats the same element along an axis
+    let layout = StridedLayout::new(0, [4, 3], [0, 1]);
+    assert_eq!(layout.cart_to_offset(&[3, 2]).unwrap(), 2);
+    let cart = layout.offset_to_cart(2).unwrap();
+    assert_eq!(layout.cart_to_offset(&cart).unwrap(), 2);
+    assert!(layout.offset_to_cart(3).is_err());
+
+    // Empty layouts do not contain any element
+    let layout = StridedLayout::new(0, [4, 0], [1, 1]);
+    assert!(layout.offset_to_cart(0).is_err());
+}
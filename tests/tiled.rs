@@ -0,0 +1,149 @@
+use cart_lin::{CartesianIndices, IndexError, TiledIndices};
+use proptest::prelude::*;
+
+/// Naive reference implementation: nested loops over the tiles and the elements of each tile.
+fn nested_loops(bounds: [[usize; 2]; 2], tile_shape: [usize; 2]) -> Vec<[usize; 2]> {
+    let mut indices = Vec::new();
+    for ti in (bounds[0][0]..bounds[0][1]).step_by(tile_shape[0]) {
+        for tj in (bounds[1][0]..bounds[1][1]).step_by(tile_shape[1]) {
+            for i in ti..(ti + tile_shape[0]).min(bounds[0][1]) {
+                for j in tj..(tj + tile_shape[1]).min(bounds[1][1]) {
+                    indices.push([i, j]);
+                }
+            }
+        }
+    }
+    indices
+}
+
+fn bounds_strategy() -> impl Strategy<Value = [[usize; 2]; 2]> {
+    [(0usize..5, 1usize..12), (0usize..5, 1usize..12)]
+        .prop_map(|axes| axes.map(|(start, len)| [start, start + len]))
+}
+
+#[test]
+fn test_tiles() {
+    // Divisible shape
+    let tiles: Vec<_> = TiledIndices::new([4, 4], [2, 2]).unwrap().collect();
+    assert_eq!(
+        tiles,
+        [
+            [[0, 2], [0, 2]],
+            [[0, 2], [2, 4]],
+            [[2, 4], [0, 2]],
+            [[2, 4], [2, 4]]
+        ]
+    );
+
+    // Tiles larger than the array
+    let tiles: Vec<_> = TiledIndices::new([3, 2], [8, 8]).unwrap().collect();
+    assert_eq!(tiles, [[[0, 3], [0, 2]]]);
+
+    // Reverse and random access
+    let mut tiles = TiledIndices::new([5, 5], [2, 3]).unwrap();
+    assert_eq!(tiles.len(), 6);
+    assert_eq!(tiles.next_back(), Some([[4, 5], [3, 5]]));
+    assert_eq!(tiles.nth(2), Some([[2, 4], [0, 3]]));
+    assert_eq!(tiles.len(), 2);
+
+    // Clipping at the end of the index type
+    let tiles: Vec<_> = TiledIndices::<1, u8>::from_bounds([[200, 255]], [50])
+        .unwrap()
+        .collect();
+    assert_eq!(tiles, [[[200, 250]], [[250, 255]]]);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        TiledIndices::new([4, 4], [0, 2]).unwrap_err(),
+        IndexError::InvalidStep { axis: 0, step: 0 }
+    );
+    assert_eq!(
+        TiledIndices::<1, i32>::new([4], [-2]).unwrap_err(),
+        IndexError::InvalidStep { axis: 0, step: -2 }
+    );
+    assert_eq!(
+        TiledIndices::<1, i32>::new([-4], [2]).unwrap_err(),
+        IndexError::NegativeDimension { axis: 0, size: -4 }
+    );
+    assert_eq!(
+        TiledIndices::from_bounds([[2, 2]], [2]).unwrap_err(),
+        IndexError::InvalidBounds {
+            axis: 0,
+            start: 2,
+            end: 2
+        }
+    );
+    assert_eq!(
+        TiledIndices::new([usize::MAX, 2], [2, 2]).unwrap_err(),
+        IndexError::Overflow
+    );
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(TiledIndices::new([0, 3], [2, 2]).unwrap().len(), 0);
+    let mut elements = TiledIndices::new([3, 0], [2, 2]).unwrap().elements();
+    assert_eq!(elements.len(), 0);
+    assert_eq!(elements.next(), None);
+    assert_eq!(elements.next_back(), None);
+}
+
+proptest! {
+    #[test]
+    fn prop_matches_nested_loops(bounds in bounds_strategy(), tile_shape in [1usize..6, 1usize..6]) {
+        let reference = nested_loops(bounds, tile_shape);
+        let tiles = TiledIndices::from_bounds(bounds, tile_shape).unwrap();
+
+        // Each tile yields its part of the reference
+        let from_tiles: Vec<_> = tiles
+            .clone()
+            .flat_map(|tile| CartesianIndices::from_bounds(tile).unwrap())
+            .collect();
+        prop_assert_eq!(&from_tiles, &reference);
+
+        let elements = tiles.elements();
+        prop_assert_eq!(elements.len(), reference.len());
+        prop_assert_eq!(elements.clone().collect::<Vec<_>>(), reference.clone());
+        prop_assert_eq!(
+            elements.rev().collect::<Vec<_>>(),
+            reference.iter().rev().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn prop_mixed_front_and_back(
+        bounds in bounds_strategy(),
+        tile_shape in [1usize..6, 1usize..6],
+        steps in prop::collection::vec((any::<bool>(), 0usize..8), 0..40),
+    ) {
+        let reference = nested_loops(bounds, tile_shape);
+        let mut elements = TiledIndices::from_bounds(bounds, tile_shape).unwrap().elements();
+        let mut front = 0;
+        let mut back = reference.len();
+        for (from_back, n) in steps {
+            if from_back {
+                let expected = if front + n < back {
+                    back -= n + 1;
+                    Some(reference[back])
+                } else {
+                    back = front;
+                    None
+                };
+                prop_assert_eq!(elements.nth_back(n), expected);
+            } else {
+                let expected = if front + n < back {
+                    front += n + 1;
+                    Some(reference[front - 1])
+                } else {
+                    front = back;
+                    None
+                };
+                prop_assert_eq!(elements.nth(n), expected);
+            }
+            prop_assert_eq!(elements.len(), back - front);
+        }
+        prop_assert_eq!(elements.collect::<Vec<_>>(), reference[front..back].to_vec());
+    }
+}